@@ -24,6 +24,21 @@ impl ColoredChar {
         }
     }
 
+    #[must_use]
+    pub const fn character(&self) -> char {
+        self.character
+    }
+
+    #[must_use]
+    pub const fn color(&self) -> Option<termcolor::Color> {
+        self.color
+    }
+
+    #[must_use]
+    pub const fn background(&self) -> Option<termcolor::Color> {
+        self.background
+    }
+
     pub fn write_to_buffer(&self, buffer: &mut Buffer) -> Result<(), fmt::Error> {
         buffer
             .set_color(ColorSpec::new().set_fg(self.color).set_bg(self.background))
@@ -75,13 +90,42 @@ impl ColoredString {
         self.vec.extend(other.vec.iter().cloned());
     }
 
-    pub fn iter(&self) -> std::slice::Iter<ColoredChar> {
+    pub fn iter(&self) -> std::slice::Iter<'_, ColoredChar> {
         self.vec.iter()
     }
 
     pub fn len(&self) -> usize {
         self.vec.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Shortens the string to `width` characters, replacing the last visible character
+    /// with an ellipsis (keeping its colors) when something had to be cut.
+    pub fn truncate(&mut self, width: usize) {
+        if self.vec.len() <= width {
+            return;
+        }
+        self.vec.truncate(width);
+        if let Some(last) = self.vec.last_mut() {
+            last.character = '…';
+        }
+    }
+
+    /// Pads the string with uncolored spaces until it is `width` characters long.
+    pub fn pad_to(&mut self, width: usize) {
+        while self.vec.len() < width {
+            self.push(ColoredChar::new(' ', None, None));
+        }
+    }
+}
+
+impl Default for ColoredString {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl From<Vec<ColoredChar>> for ColoredString {
@@ -1,10 +1,10 @@
 //! Module that handles the interaction with the various APIs used to gather data.
 
 use crate::{
-    cache,
+    cache::{self, rank_history::RankHistory},
     cli::lolfetch::InfoKind,
     config::{Config, Image},
-    models::matches::MatchInfo,
+    models::{matches::MatchInfo, ranked::RankSnapshot},
 };
 use account::Fetcher as AccountFetcher;
 use anyhow::Result;
@@ -19,25 +19,38 @@ use riven::{
 use tooling::static_data::IconGetter;
 
 pub mod account;
+pub mod live;
+pub mod live_client;
 pub mod mastery;
 pub mod matches;
+pub mod player;
 pub mod rank;
 pub mod tooling;
 
 /// Extension trait to fetch data from the API.
 pub trait Fetcher {
     async fn fetch(&self, config: &Config) -> Result<Data>;
+
+    /// Fetches the data again, reusing the summoner and the matches of the previous fetch
+    /// so that only the new matches are fetched, even when the cache is not saved.
+    async fn refresh(&self, config: &Config, previous: &Data) -> Result<Data>;
+}
+
+trait Retriever {
+    async fn fetch_from(&self, config: &Config, previous: Option<&Data>) -> Result<Data>;
 }
 
 /// Data struct to holds the various data fetched from the API.
 /// Each field is linked to specific data / module.
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Data {
     /// Summoner information.
     pub summoner: summoner_v4::Summoner,
     /// Ranked information.
     pub ranked: Option<league_v4::LeagueEntry>,
+    /// Recorded ranks in ranked solo, sorted chronologically.
+    pub rank_history: Vec<RankSnapshot>,
     /// Matches.
     pub matches: Option<Vec<MatchInfo>>,
     /// Champion masteries.
@@ -50,39 +63,68 @@ pub struct Data {
 
 impl Fetcher for RiotApi {
     async fn fetch(&self, config: &Config) -> Result<Data> {
+        self.fetch_from(config, None).await
+    }
+
+    async fn refresh(&self, config: &Config, previous: &Data) -> Result<Data> {
+        self.fetch_from(config, Some(previous)).await
+    }
+}
+
+impl Retriever for RiotApi {
+    async fn fetch_from(&self, config: &Config, previous: Option<&Data>) -> Result<Data> {
         info!("Fetching data from Riot API");
 
         // Construct commonly used data structs for fetching data.
-        let summoner = self.fetch_summoner(&config.account).await?;
+        let summoner = match previous {
+            Some(previous) => previous.summoner.clone(),
+            None => self.fetch_summoner(&config.account).await?,
+        };
 
         // Get cached data
         let mut cache =
             cache::Cache::load_cache_from_file(summoner.clone(), config.account.server)?;
+        if let Some(matches) = previous.and_then(|previous| previous.matches.clone()) {
+            cache.extend(matches);
+        }
 
         // Ranked information.
         let ranked = self
             .fetch_rank(&summoner, QueueType::RANKED_SOLO_5x5, config)
             .await?;
 
+        let mut rank_history = RankHistory::load(&summoner, config.account.server)?;
+        if let Some(ranked) = &ranked {
+            rank_history.record(ranked);
+            if config.globals.cache_save.should_save() {
+                rank_history.save()?;
+            }
+        }
+
         // TODO: Handle error better
         let criteria = config
             .mode
             .to_match_criteria()
             .ok_or(anyhow::anyhow!("Invalid mode for fetching matches"))?;
 
-        let matches = match self
-            .fetch_recent_matches(
-                &summoner,
-                config.account.server.to_regional(),
-                &cache,
-                &criteria,
-            )
-            .await?
-        {
-            Some(matches) => matches,
-            None => {
-                anyhow::bail!("The summoner does not have any matches to display");
+        // Only the cached games are used when no game is requested
+        let matches = if criteria.count > 0 {
+            match self
+                .fetch_recent_matches(
+                    &summoner,
+                    config.account.server.to_regional(),
+                    &cache,
+                    &criteria,
+                )
+                .await?
+            {
+                Some(matches) => matches,
+                None => {
+                    anyhow::bail!("The summoner does not have any matches to display");
+                }
             }
+        } else {
+            Vec::new()
         };
 
         for info in matches {
@@ -152,6 +194,7 @@ impl Fetcher for RiotApi {
         Ok(Data {
             summoner,
             ranked,
+            rank_history: rank_history.snapshots(&QueueType::RANKED_SOLO_5x5),
             matches: Some(matches),
             masteries,
             image_url,
@@ -31,6 +31,13 @@ impl RiotId {
         let (username, tagline) = riot_id.split_once('#').ok_or(RiotIdError::InvalidFormat)?;
         Ok(Self::new(username, tagline))
     }
+
+    /// Returns whether the Riot ID is `game_name#tagline`, ignoring the case like the client does.
+    pub fn matches(&self, game_name: &str, tagline: &str) -> bool {
+        // Game names are not limited to ASCII, e.g. `Ünal` or `Игрок`
+        self.username.to_lowercase() == game_name.to_lowercase()
+            && self.tagline.to_lowercase() == tagline.to_lowercase()
+    }
 }
 
 impl fmt::Display for RiotId {
@@ -113,3 +120,19 @@ impl Fetcher for riven::RiotApi {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("Faker#KR1", "faker", "kr1" => true ; "ascii")]
+    #[test_case("Игрок#EUW", "игрок", "euw" => true ; "cyrillic")]
+    #[test_case("Ünal#EUW", "ünal", "EUW" => true ; "latin accent")]
+    #[test_case("Faker#KR1", "Player", "KR1" => false ; "other name")]
+    fn test_matches(riot_id: &str, game_name: &str, tagline: &str) -> bool {
+        RiotId::from_str(riot_id)
+            .expect("Riot ID should be valid")
+            .matches(game_name, tagline)
+    }
+}
@@ -0,0 +1,178 @@
+//! Game the summoner is currently playing, from the spectator API.
+
+use super::matches::{Fetcher as MatchesFetcher, MatchCriteria};
+use crate::{
+    cache::{self, CacheSaveOptions},
+    models::{champion_stats::GameStats, matches::MatchPlayerInfo},
+};
+use riven::{
+    consts::{Champion, PlatformRoute, QueueType, Team},
+    models::{
+        league_v4::LeagueEntry,
+        spectator_v5::{CurrentGameInfo, CurrentGameParticipant},
+        summoner_v4::Summoner,
+    },
+    RiotApi,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FetcherError {
+    #[error("Failed to fetch the live game: {0}")]
+    FetchError(#[from] riven::RiotApiError),
+}
+
+/// Game in progress and the players taking part in it.
+pub struct LiveGame {
+    pub info: CurrentGameInfo,
+    /// Players in the order of the spectator API, blue side first.
+    pub players: Vec<LivePlayer>,
+}
+
+pub struct LivePlayer {
+    pub participant: CurrentGameParticipant,
+    /// Rank in ranked solo, `None` if unranked or not fetched.
+    pub ranked: Option<LeagueEntry>,
+    /// Statistics of the player on their champion, from their cached and recent games.
+    /// Only fetched for the opponents of the summoner.
+    pub champion_stats: Option<GameStats>,
+}
+
+pub trait Fetcher {
+    /// Fetches the game the summoner is playing, `None` if not in game.
+    /// The champion statistics of the opponents are computed from their cached games and their last `games` games.
+    async fn fetch_live_game(
+        &self,
+        summoner: &Summoner,
+        route: PlatformRoute,
+        games: i32,
+        cache_save: CacheSaveOptions,
+    ) -> Result<Option<LiveGame>, FetcherError>;
+}
+
+impl Fetcher for RiotApi {
+    async fn fetch_live_game(
+        &self,
+        summoner: &Summoner,
+        route: PlatformRoute,
+        games: i32,
+        cache_save: CacheSaveOptions,
+    ) -> Result<Option<LiveGame>, FetcherError> {
+        info!("Fetching live game");
+
+        let Some(info) = self
+            .spectator_v5()
+            .get_current_game_info_by_puuid(route, &summoner.puuid)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let team = info
+            .participants
+            .iter()
+            .find(|participant| participant.puuid.as_deref() == Some(summoner.puuid.as_str()))
+            .map(|participant| participant.team_id);
+
+        let mut players = Vec::new();
+        for participant in &info.participants {
+            let ranked = if participant.bot {
+                None
+            } else {
+                self.league_v4()
+                    .get_league_entries_for_summoner(route, &participant.summoner_id)
+                    .await?
+                    .into_iter()
+                    .find(|entry| entry.queue_type == QueueType::RANKED_SOLO_5x5)
+            };
+
+            let is_opponent = team.is_some_and(|team: Team| team != participant.team_id);
+            let champion_stats = match &participant.puuid {
+                Some(puuid) if is_opponent && !participant.bot => {
+                    let criteria = MatchCriteria {
+                        count: games,
+                        queue: info.game_queue_config_id,
+                        start_at: None,
+                    };
+                    match self
+                        .fetch_champion_stats(
+                            puuid,
+                            participant.champion_id,
+                            route,
+                            &criteria,
+                            cache_save,
+                        )
+                        .await
+                    {
+                        Ok(stats) => Some(stats),
+                        Err(e) => {
+                            warn!("Failed to fetch the games of an opponent: {e:?}");
+                            None
+                        }
+                    }
+                }
+                _ => None,
+            };
+
+            players.push(LivePlayer {
+                participant: participant.clone(),
+                ranked,
+                champion_stats,
+            });
+        }
+
+        Ok(Some(LiveGame { info, players }))
+    }
+}
+
+trait Retriever {
+    /// Returns the statistics of a player on a champion, from their cached games and the games matching the criteria.
+    async fn fetch_champion_stats(
+        &self,
+        puuid: &str,
+        champion: Champion,
+        route: PlatformRoute,
+        criteria: &MatchCriteria,
+        cache_save: CacheSaveOptions,
+    ) -> anyhow::Result<GameStats>;
+}
+
+impl Retriever for RiotApi {
+    async fn fetch_champion_stats(
+        &self,
+        puuid: &str,
+        champion: Champion,
+        route: PlatformRoute,
+        criteria: &MatchCriteria,
+        cache_save: CacheSaveOptions,
+    ) -> anyhow::Result<GameStats> {
+        let summoner = self.summoner_v4().get_by_puuid(route, puuid).await?;
+
+        let mut cache = cache::Cache::load_cache_from_file(summoner.clone(), route)?;
+        let fetched = self
+            .fetch_recent_matches(&summoner, route.to_regional(), &cache, criteria)
+            .await?
+            .unwrap_or_default();
+        for info in fetched {
+            if let Err(e) = cache.insert(info.id.clone(), info).await {
+                warn!("Failed to insert match into cache: {e:?}");
+            }
+        }
+
+        // The cached games of other queues are kept, but not counted
+        let mut stats = GameStats::new();
+        cache
+            .save(cache_save)?
+            .iter()
+            .filter(|game| {
+                criteria
+                    .queue
+                    .is_none_or(|queue| game.info.queue_id == queue)
+            })
+            .filter_map(|game| MatchPlayerInfo::from_match_info(game, &summoner).ok())
+            .filter(|player_info| player_info.champion == champion)
+            .for_each(|player_info| stats.add_game(&player_info));
+
+        Ok(stats)
+    }
+}
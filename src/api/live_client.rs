@@ -0,0 +1,205 @@
+//! [Live Client Data API](https://developer.riotgames.com/docs/lol#game-client-api_live-client-data-api),
+//! served by the game client on the computer of the player while a game is running.
+//!
+//! It does not need an API key, but is only available during games.
+
+use serde::Deserialize;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Endpoint of the game client.
+pub const DEFAULT_ENDPOINT: &str = "https://127.0.0.1:2999";
+
+/// Time after which the client is considered unavailable.
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Error, Debug)]
+pub enum LiveClientError {
+    #[error("Failed to create the HTTP client: {0}")]
+    Client(reqwest::Error),
+
+    #[error("Failed to fetch live client data: {0}")]
+    Fetch(#[from] reqwest::Error),
+}
+
+/// Data of the game in progress, as returned by `/liveclientdata/allgamedata`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllGameData {
+    pub active_player: ActivePlayer,
+    pub all_players: Vec<Player>,
+    pub game_data: GameData,
+}
+
+/// Player of the computer the client runs on.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivePlayer {
+    /// Riot ID of the player, `game_name#tagline`.
+    #[serde(default)]
+    pub riot_id: String,
+    #[serde(default)]
+    pub summoner_name: String,
+    pub current_gold: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Player {
+    #[serde(default)]
+    pub riot_id: String,
+    #[serde(default)]
+    pub summoner_name: String,
+    pub champion_name: String,
+    pub level: i32,
+    pub is_dead: bool,
+    pub respawn_timer: f64,
+    /// `ORDER` for the blue side, `CHAOS` for the red side.
+    pub team: String,
+    pub items: Vec<Item>,
+    pub scores: Scores,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Item {
+    pub display_name: String,
+    pub slot: i32,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Scores {
+    pub kills: i32,
+    pub deaths: i32,
+    pub assists: i32,
+    pub creep_score: i32,
+    pub ward_score: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameData {
+    pub game_mode: String,
+    /// Time since the start of the game, in seconds.
+    pub game_time: f64,
+}
+
+impl AllGameData {
+    /// Returns the player the client runs for, among all the players.
+    pub fn me(&self) -> Option<&Player> {
+        let active = &self.active_player;
+        self.all_players.iter().find(|player| {
+            if active.riot_id.is_empty() {
+                player.summoner_name == active.summoner_name
+            } else {
+                player.riot_id == active.riot_id
+            }
+        })
+    }
+}
+
+/// Client of the Live Client Data API.
+pub struct LiveClient {
+    client: reqwest::Client,
+    endpoint: String,
+}
+
+impl LiveClient {
+    /// Creates a client for the endpoint, e.g. [`DEFAULT_ENDPOINT`].
+    ///
+    /// The game client uses a certificate signed by Riot's own authority, so certificates are not verified.
+    pub fn new(endpoint: &str) -> Result<Self, LiveClientError> {
+        let client = reqwest::Client::builder()
+            .danger_accept_invalid_certs(true)
+            .timeout(TIMEOUT)
+            .build()
+            .map_err(LiveClientError::Client)?;
+
+        Ok(Self {
+            client,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Fetches the data of the game in progress, `None` if the client is not running or the game is loading.
+    pub async fn fetch(&self) -> Result<Option<AllGameData>, LiveClientError> {
+        let url = format!("{}/liveclientdata/allgamedata", self.endpoint);
+        let response = match self.client.get(&url).send().await {
+            Ok(response) => response,
+            Err(e) if e.is_connect() || e.is_timeout() => {
+                info!("Live client unavailable: {e}");
+                return Ok(None);
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        // The client answers 404 until the game has loaded
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        Ok(Some(response.error_for_status()?.json().await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Response of the client recorded during a game, trimmed to two players.
+    const RECORDED: &str = r#"{
+        "activePlayer": {
+            "abilities": {},
+            "championStats": { "attackDamage": 74.0 },
+            "currentGold": 512.3,
+            "level": 9,
+            "riotId": "abc#1234",
+            "summonerName": "abc#1234"
+        },
+        "allPlayers": [
+            {
+                "championName": "Ahri",
+                "isBot": false,
+                "isDead": false,
+                "items": [
+                    { "canUse": false, "consumable": false, "count": 1, "displayName": "Luden's Companion", "itemID": 6655, "price": 1050, "rawDescription": "", "rawDisplayName": "", "slot": 0 }
+                ],
+                "level": 9,
+                "position": "MIDDLE",
+                "riotId": "abc#1234",
+                "respawnTimer": 0.0,
+                "scores": { "assists": 4, "creepScore": 110, "deaths": 1, "kills": 3, "wardScore": 6.5 },
+                "summonerName": "abc#1234",
+                "team": "ORDER"
+            },
+            {
+                "championName": "Zed",
+                "isBot": false,
+                "isDead": true,
+                "items": [],
+                "level": 8,
+                "position": "MIDDLE",
+                "riotId": "def#5678",
+                "respawnTimer": 12.5,
+                "scores": { "assists": 0, "creepScore": 95, "deaths": 3, "kills": 1, "wardScore": 2.0 },
+                "summonerName": "def#5678",
+                "team": "CHAOS"
+            }
+        ],
+        "events": { "Events": [] },
+        "gameData": { "gameMode": "CLASSIC", "gameTime": 845.2, "mapName": "Map11", "mapNumber": 11, "mapTerrain": "Default" }
+    }"#;
+
+    #[test]
+    fn test_parse_recorded_response() {
+        let data: AllGameData = serde_json::from_str(RECORDED).unwrap();
+
+        let me = data.me().unwrap();
+        assert_eq!(me.champion_name, "Ahri");
+        assert_eq!(me.scores.creep_score, 110);
+        assert_eq!(me.items[0].display_name, "Luden's Companion");
+        assert_eq!(data.game_data.game_time, 845.2);
+        assert!(data.all_players[1].is_dead);
+    }
+}
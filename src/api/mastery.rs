@@ -14,12 +14,11 @@ pub enum RetrieverError {
 
 /// Retrieves champion masteries.
 trait Retriever {
-    /// Returns X champion masteries of a summoner.
+    /// Returns every champion mastery of a summoner, sorted by points.
     async fn get_mastery(
         &self,
         summoner: &Summoner,
         route: PlatformRoute,
-        count: i32,
     ) -> Result<Vec<ChampionMastery>, RetrieverError>;
 }
 
@@ -28,10 +27,9 @@ impl Retriever for RiotApi {
         &self,
         summoner: &Summoner,
         route: PlatformRoute,
-        count: i32,
     ) -> Result<Vec<ChampionMastery>, RetrieverError> {
         self.champion_mastery_v4()
-            .get_top_champion_masteries_by_puuid(route, &summoner.id, Some(count))
+            .get_all_champion_masteries_by_puuid(route, &summoner.puuid)
             .await
             .map_err(RetrieverError::FetchError)
     }
@@ -44,7 +42,7 @@ pub enum FetcherError {
 }
 
 pub trait Fetcher {
-    /// Fetches X champion masteries of a summoner.
+    /// Fetches every champion mastery of a summoner.
     async fn fetch_mastery(
         &self,
         summoner: &Summoner,
@@ -61,13 +59,11 @@ impl Fetcher for RiotApi {
         mode: &cli::lolfetch::InfoKind,
     ) -> Result<Option<Vec<ChampionMastery>>, FetcherError> {
         match mode {
-            InfoKind::Mastery(mastery) => {
-                let count = mastery.games;
-                self.get_mastery(summoner, route, count)
-                    .await
-                    .map(Some)
-                    .map_err(FetcherError::FetchError)
-            }
+            InfoKind::Mastery(_) => self
+                .get_mastery(summoner, route)
+                .await
+                .map(Some)
+                .map_err(FetcherError::FetchError),
             _ => Ok(None),
         }
     }
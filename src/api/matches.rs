@@ -23,7 +23,7 @@ trait Retriever {
         summoner: &Summoner,
         route: RegionalRoute,
         match_criteria: &MatchCriteria,
-    ) -> Result<HashSet<String>, riven::RiotApiError>;
+    ) -> Result<HashSet<String>, IdRetrieverError>;
 }
 
 impl Retriever for RiotApi {
@@ -32,7 +32,7 @@ impl Retriever for RiotApi {
         summoner: &Summoner,
         route: RegionalRoute,
         match_criteria: &MatchCriteria,
-    ) -> Result<HashSet<String>, riven::RiotApiError> {
+    ) -> Result<HashSet<String>, IdRetrieverError> {
         const MAX_MATCHES_PER_REQUEST: i32 = 100;
 
         let mut match_ids: HashSet<String> = HashSet::new();
@@ -96,6 +96,9 @@ impl InfoKind {
 
 #[derive(Error, Debug)]
 pub enum FetcherError {
+    #[error("{0}")]
+    IdFetchError(#[from] IdRetrieverError),
+
     #[error("Failed to retrieve matches: {0}")]
     FetchError(#[from] riven::RiotApiError),
 }
@@ -110,6 +113,13 @@ pub trait Fetcher {
         cache: &cache::Cache,
         criteria: &MatchCriteria,
     ) -> Result<Option<Vec<MatchInfo>>, FetcherError>;
+
+    /// Fetches a match and its timeline, `None` if the match does not exist.
+    async fn fetch_match(
+        &self,
+        route: RegionalRoute,
+        id: &str,
+    ) -> Result<Option<MatchInfo>, FetcherError>;
 }
 
 impl Fetcher for RiotApi {
@@ -128,25 +138,10 @@ impl Fetcher for RiotApi {
 
         for id in ids {
             if !cache.contains(&id) {
-                info!("Fetching match {id}");
-
-                let match_info = self
-                    .match_v5()
-                    .get_match(route, &id)
+                let info = self
+                    .fetch_match(route, &id)
                     .await?
                     .expect("Match not found");
-                let timeline = self
-                    .match_v5()
-                    .get_timeline(route, &id)
-                    .await?
-                    .expect("Timeline not found");
-
-                let info = MatchInfo {
-                    id: match_info.metadata.match_id.clone(),
-                    info: match_info.info,
-                    timeline: Some(timeline.info),
-                };
-
                 matches.push(info);
             } else {
                 info!("Ignoring match {id} : cached");
@@ -155,4 +150,27 @@ impl Fetcher for RiotApi {
 
         Ok(Some(matches))
     }
+
+    async fn fetch_match(
+        &self,
+        route: RegionalRoute,
+        id: &str,
+    ) -> Result<Option<MatchInfo>, FetcherError> {
+        info!("Fetching match {id}");
+
+        let Some(match_info) = self.match_v5().get_match(route, id).await? else {
+            return Ok(None);
+        };
+        let timeline = self
+            .match_v5()
+            .get_timeline(route, id)
+            .await?
+            .expect("Timeline not found");
+
+        Ok(Some(MatchInfo {
+            id: match_info.metadata.match_id.clone(),
+            info: match_info.info,
+            timeline: Some(timeline.info),
+        }))
+    }
 }
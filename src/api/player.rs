@@ -0,0 +1,93 @@
+//! Ranked data of a player, used by the views showing several players at once.
+
+use super::{
+    account::Fetcher as AccountFetcher,
+    matches::{Fetcher as MatchesFetcher, MatchCriteria},
+    rank::Fetcher as RankFetcher,
+};
+use crate::{
+    api::account::RiotId,
+    cache::{self, rank_history::RankHistory, CacheSaveOptions},
+    config::Account,
+    models::matches::MatchInfo,
+};
+use anyhow::Result;
+use riven::{
+    consts::{Queue, QueueType},
+    models::{league_v4::LeagueEntry, summoner_v4::Summoner},
+    RiotApi,
+};
+
+/// Ranked solo information and recent ranked solo games of a player.
+#[derive(Debug)]
+pub struct PlayerData {
+    pub riot_id: RiotId,
+    pub summoner: Summoner,
+    pub ranked: Option<LeagueEntry>,
+    /// Ranked solo games, from the most recent.
+    pub matches: Vec<MatchInfo>,
+}
+
+pub trait Fetcher {
+    /// Fetches the rank of a player and their last `games` ranked solo games, going through the cache.
+    async fn fetch_player(
+        &self,
+        account: &Account,
+        games: i32,
+        cache_save: CacheSaveOptions,
+    ) -> Result<PlayerData>;
+}
+
+impl Fetcher for RiotApi {
+    async fn fetch_player(
+        &self,
+        account: &Account,
+        games: i32,
+        cache_save: CacheSaveOptions,
+    ) -> Result<PlayerData> {
+        info!("Fetching player {}", account.riot_id);
+
+        let summoner = self.fetch_summoner(account).await?;
+
+        let ranked = self
+            .fetch_queue_rank(&summoner, account.server, QueueType::RANKED_SOLO_5x5)
+            .await?;
+        if let Some(ranked) = &ranked {
+            let mut rank_history = RankHistory::load(&summoner, account.server)?;
+            rank_history.record(ranked);
+            if cache_save.should_save() {
+                rank_history.save()?;
+            }
+        }
+
+        let mut cache = cache::Cache::load_cache_from_file(summoner.clone(), account.server)?;
+        let criteria = MatchCriteria {
+            count: games,
+            queue: Some(Queue::SUMMONERS_RIFT_5V5_RANKED_SOLO),
+            start_at: None,
+        };
+        let fetched = self
+            .fetch_recent_matches(&summoner, account.server.to_regional(), &cache, &criteria)
+            .await?
+            .unwrap_or_default();
+        for info in fetched {
+            if let Err(e) = cache.insert(info.id.clone(), info).await {
+                warn!("Failed to insert match into cache: {e:?}");
+            }
+        }
+
+        let mut matches = cache
+            .save(cache_save)?
+            .into_iter()
+            .filter(|game| game.info.queue_id == Queue::SUMMONERS_RIFT_5V5_RANKED_SOLO)
+            .collect::<Vec<_>>();
+        matches.truncate(games as usize);
+
+        Ok(PlayerData {
+            riot_id: account.riot_id.clone(),
+            summoner,
+            ranked,
+            matches,
+        })
+    }
+}
@@ -59,6 +59,13 @@ pub enum FetcherError {
 }
 
 pub trait Fetcher {
+    /// Fetches the ranks of a summoner in every queue.
+    async fn fetch_ranks(
+        &self,
+        summonner: &Summoner,
+        route: PlatformRoute,
+    ) -> Result<Vec<LeagueEntry>, FetcherError>;
+
     /// Fetches the rank of a summoner.
     async fn fetch_rank(
         &self,
@@ -66,9 +73,29 @@ pub trait Fetcher {
         queue: QueueType,
         config: &Config,
     ) -> Result<Option<LeagueEntry>, FetcherError>;
+
+    /// Fetches the rank of a summoner in a queue, `None` if they are unranked in it.
+    async fn fetch_queue_rank(
+        &self,
+        summonner: &Summoner,
+        route: PlatformRoute,
+        queue: QueueType,
+    ) -> Result<Option<LeagueEntry>, FetcherError>;
 }
 
 impl Fetcher for RiotApi {
+    async fn fetch_ranks(
+        &self,
+        summonner: &Summoner,
+        route: PlatformRoute,
+    ) -> Result<Vec<LeagueEntry>, FetcherError> {
+        Ok(self
+            .league_v4()
+            .get_league_entries_for_summoner(route, &summonner.id)
+            .await
+            .map_err(RetrieverError::from)?)
+    }
+
     async fn fetch_rank(
         &self,
         summonner: &Summoner,
@@ -84,4 +111,17 @@ impl Fetcher for RiotApi {
             Ok(None)
         }
     }
+
+    async fn fetch_queue_rank(
+        &self,
+        summonner: &Summoner,
+        route: PlatformRoute,
+        queue: QueueType,
+    ) -> Result<Option<LeagueEntry>, FetcherError> {
+        match self.get_rank(summonner, route, queue).await {
+            Ok(entry) => Ok(Some(entry)),
+            Err(RetrieverError::NoRankFound(_)) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
 }
@@ -6,9 +6,12 @@ use riven::{
     consts::{Champion, Tier},
     models::summoner_v4::Summoner,
 };
+use serde::Deserialize;
+use std::collections::HashMap;
 use tokio::sync::OnceCell;
 
 static ONCE: OnceCell<String> = OnceCell::const_new();
+static NAMES: OnceCell<StaticNames> = OnceCell::const_new();
 
 pub async fn get_latest_patch() -> &'static String {
     ONCE.get_or_init(|| async {
@@ -24,6 +27,184 @@ pub async fn get_latest_patch() -> &'static String {
     .await
 }
 
+/// Gold from which a completed item is considered part of the core build, leaving out boots and starters.
+const CORE_ITEM_MIN_GOLD: i32 = 2000;
+
+/// Item of the static data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub name: String,
+    /// Total cost of the item.
+    pub gold: i32,
+    /// Whether the item does not build into another one.
+    pub completed: bool,
+}
+
+impl Item {
+    const fn is_core(&self) -> bool {
+        self.completed && self.gold >= CORE_ITEM_MIN_GOLD
+    }
+}
+
+/// Names of the items, runes and summoner spells, from the Data Dragon of the latest patch.
+#[derive(Debug, Default)]
+pub struct StaticNames {
+    items: HashMap<i32, Item>,
+    /// Runes and rune trees.
+    runes: HashMap<i32, String>,
+    summoner_spells: HashMap<i32, String>,
+}
+
+impl StaticNames {
+    pub fn item_name(&self, id: i32) -> String {
+        self.items
+            .get(&id)
+            .map_or_else(|| format!("Item {id}"), |item| item.name.clone())
+    }
+
+    /// Returns whether the item is a completed item worth at least `CORE_ITEM_MIN_GOLD`.
+    pub fn is_core_item(&self, id: i32) -> bool {
+        self.items.get(&id).is_some_and(Item::is_core)
+    }
+
+    pub fn rune_name(&self, id: i32) -> String {
+        self.runes
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| format!("Rune {id}"))
+    }
+
+    pub fn summoner_spell_name(&self, id: i32) -> String {
+        self.summoner_spells
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| format!("Spell {id}"))
+    }
+
+    /// Returns the completed items worth at least `CORE_ITEM_MIN_GOLD`, from the most expensive.
+    pub fn core_items(&self, items: &[i32]) -> Vec<i32> {
+        let mut core = items
+            .iter()
+            .filter_map(|id| Some((*id, self.items.get(id)?)))
+            .filter(|(_, item)| item.is_core())
+            .collect::<Vec<_>>();
+        core.sort_by_key(|(id, item)| (std::cmp::Reverse(item.gold), *id));
+        core.into_iter().map(|(id, _)| id).collect()
+    }
+
+    fn parse(items: &str, runes: &str, summoner_spells: &str) -> serde_json::Result<Self> {
+        #[derive(Deserialize)]
+        struct DataFile<T> {
+            data: HashMap<String, T>,
+        }
+
+        #[derive(Deserialize)]
+        struct ItemEntry {
+            name: String,
+            gold: ItemGold,
+            #[serde(default)]
+            into: Vec<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct ItemGold {
+            total: i32,
+        }
+
+        #[derive(Deserialize)]
+        struct RuneTree {
+            id: i32,
+            name: String,
+            slots: Vec<RuneSlot>,
+        }
+
+        #[derive(Deserialize)]
+        struct RuneSlot {
+            runes: Vec<Rune>,
+        }
+
+        #[derive(Deserialize)]
+        struct Rune {
+            id: i32,
+            name: String,
+        }
+
+        #[derive(Deserialize)]
+        struct SummonerSpellEntry {
+            key: String,
+            name: String,
+        }
+
+        let items = serde_json::from_str::<DataFile<ItemEntry>>(items)?
+            .data
+            .into_iter()
+            .filter_map(|(id, item)| {
+                Some((
+                    id.parse().ok()?,
+                    Item {
+                        name: item.name,
+                        gold: item.gold.total,
+                        completed: item.into.is_empty(),
+                    },
+                ))
+            })
+            .collect();
+
+        let mut rune_names = HashMap::new();
+        for tree in serde_json::from_str::<Vec<RuneTree>>(runes)? {
+            rune_names.insert(tree.id, tree.name);
+            for rune in tree.slots.into_iter().flat_map(|slot| slot.runes) {
+                rune_names.insert(rune.id, rune.name);
+            }
+        }
+
+        let summoner_spells =
+            serde_json::from_str::<DataFile<SummonerSpellEntry>>(summoner_spells)?
+                .data
+                .into_values()
+                .filter_map(|spell| Some((spell.key.parse().ok()?, spell.name)))
+                .collect();
+
+        Ok(Self {
+            items,
+            runes: rune_names,
+            summoner_spells,
+        })
+    }
+}
+
+/// Returns the names of the latest patch. If they cannot be fetched, the IDs are displayed instead.
+pub async fn get_static_names() -> &'static StaticNames {
+    NAMES
+        .get_or_init(|| async {
+            match fetch_static_names().await {
+                Ok(names) => names,
+                Err(err) => {
+                    warn!("Failed to fetch static data: {err}");
+                    StaticNames::default()
+                }
+            }
+        })
+        .await
+}
+
+async fn fetch_static_names() -> anyhow::Result<StaticNames> {
+    async fn fetch(file: &str) -> reqwest::Result<String> {
+        let url = format!(
+            "https://ddragon.leagueoflegends.com/cdn/{}/data/en_US/{file}",
+            get_latest_patch().await
+        );
+        reqwest::get(url).await?.error_for_status()?.text().await
+    }
+
+    info!("Fetching static data");
+    let items = fetch("item.json").await?;
+    let runes = fetch("runesReforged.json").await?;
+    let summoner_spells = fetch("summoner.json").await?;
+
+    Ok(StaticNames::parse(&items, &runes, &summoner_spells)?)
+}
+
 pub trait IconGetter {
     /// Returns the icon.
     async fn get_icon_url(&self) -> String;
@@ -67,3 +248,45 @@ impl IconGetter for Tier {
         }.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITEMS: &str = r#"{"data": {
+        "1001": {"name": "Boots", "gold": {"total": 300}, "into": ["3020"]},
+        "3020": {"name": "Sorcerer's Shoes", "gold": {"total": 1100}},
+        "3089": {"name": "Rabadon's Deathcap", "gold": {"total": 3600}},
+        "6655": {"name": "Luden's Companion", "gold": {"total": 2900}},
+        "3157": {"name": "Zhonya's Hourglass", "gold": {"total": 3250}}
+    }}"#;
+    const RUNES: &str = r#"[{"id": 8200, "name": "Sorcery", "slots": [
+        {"runes": [{"id": 8214, "name": "Summon Aery"}, {"id": 8229, "name": "Arcane Comet"}]}
+    ]}]"#;
+    const SUMMONER_SPELLS: &str = r#"{"data": {
+        "SummonerFlash": {"key": "4", "name": "Flash"},
+        "SummonerDot": {"key": "14", "name": "Ignite"}
+    }}"#;
+
+    fn names() -> StaticNames {
+        StaticNames::parse(ITEMS, RUNES, SUMMONER_SPELLS).unwrap()
+    }
+
+    #[test]
+    fn test_names() {
+        let names = names();
+        assert_eq!(names.item_name(3089), "Rabadon's Deathcap");
+        assert_eq!(names.item_name(1), "Item 1");
+        assert_eq!(names.rune_name(8200), "Sorcery");
+        assert_eq!(names.rune_name(8229), "Arcane Comet");
+        assert_eq!(names.summoner_spell_name(14), "Ignite");
+    }
+
+    #[test]
+    fn test_core_items() {
+        assert_eq!(
+            names().core_items(&[1001, 6655, 3020, 3089, 3157, 2003]),
+            [3089, 3157, 6655]
+        );
+    }
+}
@@ -1,18 +1,36 @@
 use crate::{
     api::{
-        account::{self, Fetcher as AccountFetcher, PuuidFetchError},
+        account::{self, Fetcher as AccountFetcher, PuuidFetchError, RiotId},
+        live::Fetcher as LiveFetcher,
+        live_client::LiveClient,
         matches::{Fetcher, MatchCriteria},
-        Fetcher as ApiFetcher,
+        player::Fetcher as PlayerFetcher,
+        rank::Fetcher as RankFetcher,
+        tooling::static_data,
+        Data as ApiData, Fetcher as ApiFetcher,
+    },
+    cache::{self, rank_history::RankHistory, CacheSaveOptions},
+    cli::{self, cache::CacheAction, lolfetch::LayoutKind, Cli, Commands},
+    config::{file::FileConfig, load_theme, Account, Config, Roster, Templates},
+    data::{
+        comparison::Comparison, live_client::LiveStats, live_game::LiveGame, matchups::Matchups,
+        refresh::RefreshSummary, scoreboard::Scoreboard, team::Team, ApplicationData,
+    },
+    display::{
+        layout::{Layout, LayoutOptions},
+        theme, DisplayableSectionKind,
     },
-    cache::{self, CacheSaveOptions},
-    cli::{self, cache::CacheAction, Cli, Commands},
-    config::Config,
-    data::ApplicationData,
-    display::Layout,
     logging,
+    models::summary::PlayerSummary,
+    tui,
+};
+use anyhow::{Context, Result};
+use crossterm::{
+    cursor,
+    terminal::{Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use anyhow::Result;
 use riven::{consts::Queue, RiotApi, RiotApiConfig};
+use std::time::Duration;
 
 pub struct App {}
 
@@ -28,23 +46,422 @@ impl App {
 
         info!("Starting lolfetch");
 
-        let api = RiotApi::new(RiotApiConfig::with_key(&cli.api_key));
+        let file_config = FileConfig::load(cli.config.as_deref())?;
+
+        // The game client does not need an API key
+        if let Commands::LiveClient(config) = cli.command {
+            return handle_live_client(config, &file_config).await;
+        }
+
+        let api = RiotApi::new(RiotApiConfig::with_key(cli.api_key()?));
         match cli.command {
-            Commands::Display(config) => handle_display(&api, config).await,
+            Commands::Display(config) => handle_display(&api, config, &file_config).await,
             Commands::Cache(cache) => handle_cache(&api, cache).await,
+            Commands::Compare(config) => handle_compare(&api, config, &file_config).await,
+            Commands::Live(config) => handle_live(&api, config, &file_config).await,
+            Commands::LiveClient(_) => unreachable!("Handled without an API key"),
+            Commands::Match(config) => handle_match(&api, config, &file_config).await,
+            Commands::Matchups(config) => handle_matchups(&api, config, &file_config).await,
+            Commands::Team(config) => handle_team(&api, config, &file_config).await,
+            Commands::Tui(config) => handle_tui(&api, config, &file_config).await,
         }
     }
 }
 
-async fn handle_display(api: &RiotApi, config: cli::lolfetch::Lolfetch) -> Result<()> {
-    let config = Config::from_cli(config)?;
+async fn handle_display(
+    api: &RiotApi,
+    config: cli::lolfetch::Lolfetch,
+    file_config: &FileConfig,
+) -> Result<()> {
+    let config = Config::from_cli(config, file_config)?;
+    theme::set(config.theme.clone());
+    if let Some(interval) = config.watch {
+        return handle_watch(api, &config, interval).await;
+    }
+
     let data = api.fetch(&config).await?;
     let processed = ApplicationData::process(data, &config).await?;
-    info!("Displaying data");
-    Layout::new(processed).display()?;
+    let layout = Layout::new(processed, config.layout);
+    match config.export {
+        Some(export) => layout.export(&export.path, export.format)?,
+        None => {
+            info!("Displaying data");
+            layout.display()?;
+        }
+    }
     Ok(())
 }
 
+/// Displays the output on the alternate screen, refreshed every `interval` until interrupted.
+async fn handle_watch(api: &RiotApi, config: &Config, interval: Duration) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    crossterm::execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+    let result = watch(api, config, interval).await;
+    crossterm::execute!(stdout, cursor::Show, LeaveAlternateScreen)?;
+    result
+}
+
+async fn watch(api: &RiotApi, config: &Config, interval: Duration) -> Result<()> {
+    let mut previous: Option<ApiData> = None;
+
+    loop {
+        let fetched = match &previous {
+            Some(previous) => api.refresh(config, previous).await,
+            None => api.fetch(config).await,
+        };
+
+        match fetched {
+            Ok(data) => {
+                let summary = RefreshSummary::new(previous.as_ref(), &data, interval);
+                let mut processed = ApplicationData::process(data.clone(), config).await?;
+                processed
+                    .sections
+                    .insert(0, DisplayableSectionKind::RefreshSummary(summary));
+
+                crossterm::execute!(
+                    std::io::stdout(),
+                    Clear(ClearType::All),
+                    cursor::MoveTo(0, 0)
+                )?;
+                Layout::new(processed, config.layout).display()?;
+                previous = Some(data);
+            }
+            // The previous output stays on screen, the next refresh may succeed
+            Err(e) if previous.is_some() => {
+                warn!("Failed to refresh: {e:?}");
+            }
+            Err(e) => return Err(e),
+        }
+
+        tokio::select! {
+            () = tokio::time::sleep(interval) => {}
+            result = tokio::signal::ctrl_c() => return Ok(result?),
+        }
+    }
+}
+
+async fn handle_matchups(
+    api: &RiotApi,
+    config: cli::matchups::Matchups,
+    file_config: &FileConfig,
+) -> Result<()> {
+    theme::set(load_theme(config.theme.as_deref(), file_config)?);
+    let templates = Templates::from_file(&file_config.templates).context("Invalid template")?;
+
+    let account = config.summoner.clone().into();
+    let summoner = api.fetch_summoner(&account).await?;
+    let matches = cache::Cache::load_cache_from_file(summoner.clone(), account.server)?
+        .save(CacheSaveOptions::NoSave)?;
+    if matches.is_empty() {
+        warn!("No cached games, run `lolfetch cache load` first");
+    }
+
+    let matchups = Matchups::new(&matches, &summoner, config.champion, &templates.matchups);
+    let layout = Layout::new(
+        ApplicationData {
+            image: Vec::new(),
+            sections: vec![DisplayableSectionKind::Matchups(matchups)],
+        },
+        LayoutOptions {
+            kind: LayoutKind::InfoOnly,
+            width: config.width,
+            ..Default::default()
+        },
+    );
+    layout.display()
+}
+
+async fn handle_match(
+    api: &RiotApi,
+    config: cli::scoreboard::Scoreboard,
+    file_config: &FileConfig,
+) -> Result<()> {
+    theme::set(load_theme(config.theme.as_deref(), file_config)?);
+    let templates = Templates::from_file(&file_config.templates).context("Invalid template")?;
+
+    let account: Account = config.summoner.clone().into();
+    let summoner = api.fetch_summoner(&account).await?;
+    let route = account.server.to_regional();
+    let save = CacheSaveOptions::from_bool(!config.no_save);
+    let mut cache = cache::Cache::load_cache_from_file(summoner.clone(), account.server)?;
+
+    let games = match &config.match_id {
+        Some(id) => {
+            let fetched = if cache.contains(id) {
+                None
+            } else {
+                let info = api
+                    .fetch_match(route, id)
+                    .await?
+                    .with_context(|| format!("Match {id} not found"))?;
+                Some(info)
+            };
+            // A game that cannot be cached (e.g. from a previous split) is still displayed
+            if let Some(info) = &fetched {
+                if let Err(e) = cache.insert(id.clone(), info.clone()).await {
+                    warn!("Failed to insert match into cache: {e:?}");
+                }
+            }
+            let cached = cache.save(save)?;
+            vec![fetched
+                .or_else(|| cached.into_iter().find(|game| &game.id == id))
+                .with_context(|| format!("Match {id} not found"))?]
+        }
+        None => {
+            let count = config.last.unwrap_or(1);
+            let criteria = MatchCriteria {
+                count,
+                queue: None,
+                start_at: None,
+            };
+            let fetched = api
+                .fetch_recent_matches(&summoner, route, &cache, &criteria)
+                .await?
+                .unwrap_or_default();
+            for info in fetched {
+                if let Err(e) = cache.insert(info.id.clone(), info).await {
+                    warn!("Failed to insert match into cache: {e:?}");
+                }
+            }
+            let mut matches = cache.save(save)?;
+            matches.truncate(count as usize);
+            matches
+        }
+    };
+    if games.is_empty() {
+        anyhow::bail!("No games found for {}", account.riot_id);
+    }
+
+    let names = static_data::get_static_names().await;
+    let layout = Layout::new(
+        ApplicationData {
+            image: Vec::new(),
+            sections: games
+                .into_iter()
+                .map(|game| {
+                    DisplayableSectionKind::Scoreboard(Scoreboard::new(
+                        game,
+                        &summoner,
+                        names,
+                        &templates.scoreboard,
+                    ))
+                })
+                .collect(),
+        },
+        LayoutOptions {
+            kind: LayoutKind::InfoOnly,
+            width: config.width,
+            ..Default::default()
+        },
+    );
+    layout.display()
+}
+
+async fn handle_compare(
+    api: &RiotApi,
+    config: cli::compare::Compare,
+    file_config: &FileConfig,
+) -> Result<()> {
+    let [first, second]: [RiotId; 2] = config
+        .riot_id
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Exactly two Riot IDs must be given"))?;
+    let (first_server, second_server) = match config.server.as_slice() {
+        [server] => (server.clone(), server.clone()),
+        [first, second] => (first.clone(), second.clone()),
+        _ => anyhow::bail!("One server, or one per player, must be given"),
+    };
+    theme::set(load_theme(config.theme.as_deref(), file_config)?);
+
+    let save = CacheSaveOptions::from_bool(!config.no_save);
+    let mut players = Vec::new();
+    for account in [
+        Account {
+            riot_id: first,
+            server: first_server.into(),
+        },
+        Account {
+            riot_id: second,
+            server: second_server.into(),
+        },
+    ] {
+        let player = api
+            .fetch_player(&account, config.games, save)
+            .await
+            .with_context(|| format!("Failed to fetch {}", account.riot_id))?;
+        players.push(PlayerSummary::new(
+            player.riot_id,
+            player.ranked,
+            &player.matches,
+            &player.summoner,
+        ));
+    }
+    let players: [PlayerSummary; 2] = players
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Two players are compared"))?;
+
+    let layout = Layout::new(
+        ApplicationData {
+            image: Vec::new(),
+            sections: vec![DisplayableSectionKind::Comparison(Comparison::new(players))],
+        },
+        LayoutOptions {
+            kind: LayoutKind::InfoOnly,
+            width: config.width,
+            ..Default::default()
+        },
+    );
+    layout.display()
+}
+
+async fn handle_live(
+    api: &RiotApi,
+    config: cli::live::Live,
+    file_config: &FileConfig,
+) -> Result<()> {
+    theme::set(load_theme(config.theme.as_deref(), file_config)?);
+    let templates = Templates::from_file(&file_config.templates).context("Invalid template")?;
+
+    let account: Account = config.summoner.clone().into();
+    let summoner = api.fetch_summoner(&account).await?;
+    let Some(game) = api
+        .fetch_live_game(
+            &summoner,
+            account.server,
+            config.games,
+            CacheSaveOptions::from_bool(!config.no_save),
+        )
+        .await?
+    else {
+        anyhow::bail!("{} is not in game", account.riot_id);
+    };
+
+    let names = static_data::get_static_names().await;
+    let layout = Layout::new(
+        ApplicationData {
+            image: Vec::new(),
+            sections: vec![DisplayableSectionKind::LiveGame(LiveGame::new(
+                game,
+                &summoner,
+                names,
+                &templates.live_game,
+            ))],
+        },
+        LayoutOptions {
+            kind: LayoutKind::InfoOnly,
+            width: config.width,
+            ..Default::default()
+        },
+    );
+    layout.display()
+}
+
+async fn handle_live_client(
+    config: cli::live_client::LiveClient,
+    file_config: &FileConfig,
+) -> Result<()> {
+    theme::set(load_theme(config.theme.as_deref(), file_config)?);
+    let client = LiveClient::new(&config.endpoint)?;
+    let interval = std::time::Duration::from_secs(config.interval);
+
+    loop {
+        let data = client.fetch().await?;
+        if !config.once {
+            crossterm::execute!(
+                std::io::stdout(),
+                crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+                crossterm::cursor::MoveTo(0, 0)
+            )?;
+        }
+
+        match data {
+            Some(data) => Layout::new(
+                ApplicationData {
+                    image: Vec::new(),
+                    sections: vec![DisplayableSectionKind::LiveStats(LiveStats::new(data))],
+                },
+                LayoutOptions {
+                    kind: LayoutKind::InfoOnly,
+                    width: config.width,
+                    ..Default::default()
+                },
+            )
+            .display()?,
+            None if config.once => {
+                anyhow::bail!("No game in progress on {}", config.endpoint)
+            }
+            // Keeps polling until a game starts
+            None => warn!("No game in progress on {}", config.endpoint),
+        }
+
+        if config.once {
+            return Ok(());
+        }
+        tokio::select! {
+            () = tokio::time::sleep(interval) => {}
+            result = tokio::signal::ctrl_c() => return Ok(result?),
+        }
+    }
+}
+
+async fn handle_team(
+    api: &RiotApi,
+    config: cli::team::Team,
+    file_config: &FileConfig,
+) -> Result<()> {
+    let roster = Roster::from_file(config.roster.as_deref(), file_config)?;
+    theme::set(load_theme(config.theme.as_deref(), file_config)?);
+    let templates = Templates::from_file(&file_config.templates).context("Invalid template")?;
+
+    // A player that cannot be fetched should not prevent seeing the rest of the team
+    let save = CacheSaveOptions::from_bool(!config.no_save);
+    let mut players = Vec::new();
+    let mut missing = Vec::new();
+    for account in &roster.members {
+        match api.fetch_player(account, config.games, save).await {
+            Ok(player) => players.push(PlayerSummary::new(
+                player.riot_id,
+                player.ranked,
+                &player.matches,
+                &player.summoner,
+            )),
+            Err(e) => {
+                warn!("Failed to fetch {}: {e:?}", account.riot_id);
+                missing.push(account.riot_id.clone());
+            }
+        }
+    }
+
+    let team = Team::new(roster.name, players, missing, config.games, templates.team);
+    let layout = Layout::new(
+        ApplicationData {
+            image: Vec::new(),
+            sections: vec![DisplayableSectionKind::Team(team)],
+        },
+        LayoutOptions {
+            kind: LayoutKind::InfoOnly,
+            width: config.width,
+            ..Default::default()
+        },
+    );
+    layout.display()
+}
+
+async fn handle_tui(api: &RiotApi, config: cli::tui::Tui, file_config: &FileConfig) -> Result<()> {
+    theme::set(load_theme(config.theme.as_deref(), file_config)?);
+
+    let account: Account = config.summoner.clone().into();
+    let summoner = api.fetch_summoner(&account).await?;
+    let matches = cache::Cache::load_cache_from_file(summoner.clone(), account.server)?
+        .save(CacheSaveOptions::NoSave)?;
+    if matches.is_empty() {
+        anyhow::bail!("No cached games, run `lolfetch cache load` first");
+    }
+
+    let names = static_data::get_static_names().await;
+    tui::run(matches, &summoner, names)
+}
+
 async fn handle_cache(api: &RiotApi, config: cli::cache::Cache) -> Result<()> {
     match config.action {
         CacheAction::Clear(config) => handle_cache_clear(api, config).await,
@@ -149,7 +566,17 @@ async fn handle_cache_load(api: &RiotApi, config: cli::cache::Load) -> Result<()
     }
 
     // Save cache to file
-    cache.save(CacheSaveOptions::from_bool(!config.no_save))?;
+    let save = CacheSaveOptions::from_bool(!config.no_save);
+    cache.save(save)?;
+
+    // Record the current ranks, so the LP of the next games can be tracked
+    let mut rank_history = RankHistory::load(&summoner, account.server)?;
+    for entry in api.fetch_ranks(&summoner, account.server).await? {
+        rank_history.record(&entry);
+    }
+    if save.should_save() {
+        rank_history.save()?;
+    }
 
     Ok(())
 }
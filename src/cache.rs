@@ -2,7 +2,10 @@
 
 use crate::models::matches::{MatchInfo, MatchMap};
 use anyhow::Context;
-use riven::{consts::PlatformRoute, models::summoner_v4::Summoner};
+use riven::{
+    consts::{PlatformRoute, Queue},
+    models::summoner_v4::Summoner,
+};
 use std::{
     collections::HashMap,
     fs::{self, create_dir_all, OpenOptions},
@@ -10,6 +13,8 @@ use std::{
     path::PathBuf,
 };
 
+pub mod rank_history;
+
 /// Returns the cache directory for lolfetch.
 fn get_cache_dir() -> io::Result<PathBuf> {
     let cache_dir = dirs::cache_dir()
@@ -35,8 +40,11 @@ fn get_summoner_cache_dir(summoner: &Summoner, route: PlatformRoute) -> io::Resu
 
 pub type MatchId = String;
 
+/// Cache of the ranked solo games of a summoner.
 pub struct Cache {
     match_info: MatchMap,
+    /// Games of other queues, returned with the cached games but never saved.
+    other_queues: MatchMap,
     cache_file_lock: fs::File,
 }
 
@@ -51,6 +59,7 @@ impl Cache {
     fn new(cache_file_lock: fs::File) -> Self {
         Self {
             match_info: HashMap::new(),
+            other_queues: HashMap::new(),
             cache_file_lock,
         }
     }
@@ -87,8 +96,14 @@ impl Cache {
             }
         };
 
+        // Older versions also saved the games of other queues, which are dropped on the next save
+        let (match_info, other_queues) = cache
+            .into_iter()
+            .partition(|(_, info)| is_ranked_solo(info));
+
         Ok(Self {
-            match_info: cache,
+            match_info,
+            other_queues,
             cache_file_lock: file,
         })
     }
@@ -98,7 +113,7 @@ impl Cache {
         match_id: MatchId,
         info: MatchInfo,
     ) -> Result<(), CacheInsertError> {
-        if self.match_info.contains_key(&match_id) {
+        if self.contains(&match_id) {
             return Err(CacheInsertError::AlreadyExists);
         }
 
@@ -110,25 +125,38 @@ impl Cache {
             return Err(CacheInsertError::PatchMismatch);
         }
 
-        self.match_info.insert(match_id, info);
+        if is_ranked_solo(&info) {
+            self.match_info.insert(match_id, info);
+        } else {
+            self.other_queues.insert(match_id, info);
+        }
         Ok(())
     }
 
-    pub fn contains(&self, match_id: &MatchId) -> bool {
-        self.match_info.contains_key(match_id)
+    /// Adds matches that were already checked on insertion, e.g. kept in memory between two refreshes.
+    pub fn extend(&mut self, matches: impl IntoIterator<Item = MatchInfo>) {
+        for info in matches {
+            let map = if is_ranked_solo(&info) {
+                &mut self.match_info
+            } else {
+                &mut self.other_queues
+            };
+            map.entry(info.id.clone()).or_insert(info);
+        }
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.match_info.is_empty()
+    pub fn contains(&self, match_id: &MatchId) -> bool {
+        self.match_info.contains_key(match_id) || self.other_queues.contains_key(match_id)
     }
 
+    /// Returns the number of cached ranked solo games.
     pub fn len(&self) -> usize {
         self.match_info.len()
     }
 
-    /// Saves the cache to storage, and returns its content.
+    /// Saves the ranked solo games to storage, and returns them with the games of other queues.
     pub fn save(mut self, to_file: CacheSaveOptions) -> anyhow::Result<Vec<MatchInfo>> {
-        if matches!(to_file, CacheSaveOptions::Save) {
+        if to_file.should_save() {
             let serialized =
                 serde_json::to_string(&self.match_info).context("Failed to serialize cache")?;
 
@@ -155,10 +183,14 @@ impl Cache {
             info!("Saved cache to file");
         }
 
-        let mut match_vec: Vec<MatchInfo> = self.match_info.into_values().collect();
+        let mut match_vec: Vec<MatchInfo> = self
+            .match_info
+            .into_values()
+            .chain(self.other_queues.into_values())
+            .collect();
 
         // Reversed sort
-        match_vec.sort_by(|a, b| b.info.game_creation.cmp(&a.info.game_creation));
+        match_vec.sort_by_key(|info| std::cmp::Reverse(info.info.game_creation));
 
         Ok(match_vec)
     }
@@ -188,6 +220,10 @@ impl Cache {
     }
 }
 
+fn is_ranked_solo(info: &MatchInfo) -> bool {
+    info.info.queue_id == Queue::SUMMONERS_RIFT_5V5_RANKED_SOLO
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CacheSaveOptions {
     Save,
@@ -0,0 +1,83 @@
+//! Rank snapshots of a summoner, recorded on each run since Riot does not provide an LP history.
+
+use super::get_summoner_cache_dir;
+use crate::models::ranked::RankSnapshot;
+use anyhow::Context;
+use riven::{
+    consts::{PlatformRoute, QueueType},
+    models::{league_v4::LeagueEntry, summoner_v4::Summoner},
+};
+use std::{
+    fs::{self, create_dir_all},
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub struct RankHistory {
+    /// Snapshots of every queue, sorted chronologically.
+    snapshots: Vec<RankSnapshot>,
+    path: PathBuf,
+}
+
+impl RankHistory {
+    pub fn load(summoner: &Summoner, route: PlatformRoute) -> anyhow::Result<Self> {
+        info!("Loading rank history for summoner");
+
+        let path = get_summoner_cache_dir(summoner, route)?.join("ranks.json");
+
+        let snapshots = if path.exists() {
+            let content = fs::read_to_string(&path).context("Failed to read rank history")?;
+            serde_json::from_str(&content).unwrap_or_else(|err| {
+                warn!("Failed to deserialize rank history: {err:?}");
+                Vec::new()
+            })
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self { snapshots, path })
+    }
+
+    /// Records the current rank, unless it did not change since the last snapshot of the queue.
+    pub fn record(&mut self, entry: &LeagueEntry) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis() as i64);
+        let Some(snapshot) = RankSnapshot::from_entry(entry, timestamp) else {
+            return;
+        };
+
+        let last = self
+            .snapshots
+            .iter()
+            .rev()
+            .find(|last| last.queue == snapshot.queue);
+        if last.is_some_and(|last| last.same_rank(&snapshot)) {
+            return;
+        }
+
+        info!("Recording rank snapshot {}", snapshot.short_rank());
+        self.snapshots.push(snapshot);
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            create_dir_all(dir)?;
+        }
+        let serialized =
+            serde_json::to_string(&self.snapshots).context("Failed to serialize rank history")?;
+        fs::write(&self.path, serialized).context("Failed to write rank history")?;
+
+        info!("Saved rank history to file");
+        Ok(())
+    }
+
+    /// Returns the snapshots of a queue, sorted chronologically.
+    pub fn snapshots(&self, queue: &QueueType) -> Vec<RankSnapshot> {
+        self.snapshots
+            .iter()
+            .filter(|snapshot| snapshot.queue == *queue)
+            .cloned()
+            .collect()
+    }
+}
@@ -3,9 +3,17 @@
 use crate::api::account::RiotId;
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 pub mod cache;
+pub mod compare;
+pub mod live;
+pub mod live_client;
 pub mod lolfetch;
+pub mod matchups;
+pub mod scoreboard;
+pub mod team;
+pub mod tui;
 
 /// Command line arguments for the application
 #[derive(Debug, Parser)]
@@ -18,9 +26,13 @@ pub struct Cli {
     #[clap(long, global = true)]
     pub verbose: bool,
 
-    /// API key for the Riot API
-    #[clap(long, default_value = "", value_parser = parse_api_key, global = true)]
-    pub api_key: String,
+    /// API key for the Riot API, read from the `RIOT_API_KEY` environment variable (or a `.env` file) if not given
+    #[clap(long, global = true)]
+    pub api_key: Option<String>,
+
+    /// Path to the configuration file, defaults to `lolfetch/config.toml` in the configuration directory
+    #[clap(long, global = true)]
+    pub config: Option<PathBuf>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -28,8 +40,30 @@ pub enum Commands {
     /// Cache management
     Cache(cache::Cache),
 
+    /// Ranked statistics of two players side by side
+    Compare(compare::Compare),
+
     /// Default lolfetch mode
     Display(lolfetch::Lolfetch),
+
+    /// Participants of the game the summoner is currently playing
+    Live(live::Live),
+
+    /// Statistics of the game in progress from the game client, refreshed until interrupted.
+    /// Does not need an API key
+    LiveClient(live_client::LiveClient),
+
+    /// Scoreboard of a game: participants, objectives and gold difference with the lane opponent
+    Match(scoreboard::Scoreboard),
+
+    /// Statistics of a champion against each lane opponent, from the cached games
+    Matchups(matchups::Matchups),
+
+    /// Rank, form and champions of the players of a roster of the configuration file
+    Team(team::Team),
+
+    /// Full-screen browser of the cached games
+    Tui(tui::Tui),
 }
 
 #[derive(Parser, Debug, Clone)]
@@ -66,14 +100,18 @@ pub enum LeagueServer {
     Pbe,
 }
 
-/// Parses the Riot API key from the command line
-fn parse_api_key(key: &str) -> Result<String> {
-    if key.is_empty() {
-        if let Err(error) = dotenv::dotenv() {
-            warn!("Failed to load .env file: {error}");
+impl Cli {
+    /// Returns the Riot API key given on the command line or in the environment.
+    /// It is only looked up by the commands using the Riot API.
+    pub fn api_key(&self) -> Result<String> {
+        match &self.api_key {
+            Some(key) => Ok(key.clone()),
+            None => {
+                if let Err(error) = dotenv::dotenv() {
+                    warn!("Failed to load .env file: {error}");
+                }
+                std::env::var("RIOT_API_KEY").context("API key not found")
+            }
         }
-        std::env::var("RIOT_API_KEY").context("API key not found")
-    } else {
-        Ok(key.to_string())
     }
 }
@@ -0,0 +1,33 @@
+//! Compare CLI module.
+
+use super::{lolfetch::parse_number_of_parsed_games, LeagueServer};
+use crate::api::account::RiotId;
+use clap::Parser;
+
+/// CLI compare arguments
+#[derive(Parser, Debug)]
+pub struct Compare {
+    /// Riot IDs of the two players (e.g. `--riot-id abc#1234 --riot-id def#5678`)
+    #[clap(long, value_parser = RiotId::from_str, num_args = 1, required = true)]
+    pub riot_id: Vec<RiotId>,
+
+    /// Server of the players, given once if both play on the same server or once per player
+    #[clap(long, required = true)]
+    pub server: Vec<LeagueServer>,
+
+    /// Number of recent ranked solo games compared
+    #[clap(long, default_value = "20", value_parser = parse_number_of_parsed_games)]
+    pub games: i32,
+
+    /// Width of the output, defaults to the width of the terminal
+    #[clap(long)]
+    pub width: Option<usize>,
+
+    /// Color theme, see `lolfetch display --help`
+    #[clap(long)]
+    pub theme: Option<String>,
+
+    /// Do not save the fetched games to the cache
+    #[clap(long)]
+    pub no_save: bool,
+}
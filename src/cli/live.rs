@@ -0,0 +1,29 @@
+//! Live CLI module.
+
+use super::{lolfetch::parse_number_of_parsed_games, SummonerConfig};
+use clap::Parser;
+
+/// CLI live arguments
+#[derive(Parser, Debug)]
+pub struct Live {
+    /// Summoner information
+    #[command(flatten)]
+    pub summoner: SummonerConfig,
+
+    /// Number of recent games of each opponent fetched to compute their winrate on their champion,
+    /// on top of their cached games
+    #[clap(long, default_value = "10", value_parser = parse_number_of_parsed_games)]
+    pub games: i32,
+
+    /// Width of the output, defaults to the width of the terminal
+    #[clap(long)]
+    pub width: Option<usize>,
+
+    /// Color theme, see `lolfetch display --help`
+    #[clap(long)]
+    pub theme: Option<String>,
+
+    /// Do not save the fetched games to the cache
+    #[clap(long)]
+    pub no_save: bool,
+}
@@ -0,0 +1,28 @@
+//! Live client CLI module.
+
+use crate::api::live_client::DEFAULT_ENDPOINT;
+use clap::Parser;
+
+/// CLI live client arguments
+#[derive(Parser, Debug)]
+pub struct LiveClient {
+    /// Endpoint of the Live Client Data API, e.g. a local server replaying recorded responses
+    #[clap(long, default_value = DEFAULT_ENDPOINT)]
+    pub endpoint: String,
+
+    /// Seconds between two refreshes
+    #[clap(long, default_value = "5", value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+
+    /// Display the statistics once and exit, instead of refreshing them until interrupted
+    #[clap(long)]
+    pub once: bool,
+
+    /// Width of the output, defaults to the width of the terminal
+    #[clap(long)]
+    pub width: Option<usize>,
+
+    /// Color theme, see `lolfetch display --help`
+    #[clap(long)]
+    pub theme: Option<String>,
+}
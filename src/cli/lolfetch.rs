@@ -1,10 +1,11 @@
 //! lolfetch CLI module
 
 use super::SummonerConfig;
+use crate::display::export::ExportFormat;
 use anyhow::{Context, Error, Result};
 use clap::{Parser, ValueEnum};
 use riven::consts::Champion;
-use std::str::FromStr;
+use std::{path::PathBuf, str::FromStr};
 
 // Crate modules
 pub mod custom;
@@ -54,6 +55,75 @@ pub struct DisplayConfig {
     /// Link to the custom image to display
     #[clap(long, required_if_eq("image", "Custom"))]
     pub custom_img_url: Option<String>,
+
+    /// Arrangement of the image and the information
+    #[clap(long, default_value = "auto")]
+    pub layout: LayoutKind,
+
+    /// Side of the image when displayed next to the information
+    #[clap(long, default_value = "left")]
+    pub image_position: ImagePosition,
+
+    /// Vertical alignment of the shortest column when displayed side by side
+    #[clap(long, default_value = "center")]
+    pub vertical_align: VerticalAlign,
+
+    /// Width of the output, defaults to the width of the terminal
+    #[clap(long)]
+    pub width: Option<usize>,
+
+    /// Color theme, either a built-in theme (default, colorblind, monochrome),
+    /// the name of a theme file in the `lolfetch/themes` configuration folder or a path to one.
+    /// Defaults to the theme of the configuration file, or `default`
+    #[clap(long)]
+    pub theme: Option<String>,
+
+    /// Exports the output to a file instead of printing it
+    #[clap(long)]
+    pub export: Option<PathBuf>,
+
+    /// Format of the exported file, guessed from its extension if not given
+    #[clap(long, requires = "export")]
+    pub export_format: Option<ExportFormat>,
+
+    /// Refreshes the output every given number of seconds on the alternate screen, until interrupted.
+    /// New games and LP changes since the last refresh are highlighted
+    #[clap(long, value_name = "SECONDS", conflicts_with = "export", value_parser = clap::value_parser!(u64).range(1..))]
+    pub watch: Option<u64>,
+}
+
+/// Arrangement of the image and the information
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum LayoutKind {
+    /// Chooses the layout depending on the terminal width
+    #[default]
+    Auto,
+
+    /// Image and information next to each other
+    SideBySide,
+
+    /// Image above the information
+    Stacked,
+
+    /// Only the information, without the image
+    InfoOnly,
+}
+
+/// Side of the image in the side-by-side layout
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum ImagePosition {
+    #[default]
+    Left,
+    Right,
+}
+
+/// Vertical alignment of a column in the side-by-side layout
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum VerticalAlign {
+    Top,
+    #[default]
+    Center,
+    Bottom,
 }
 
 /// Image display options
@@ -96,13 +166,41 @@ pub enum InfoKind {
     Custom(custom::Custom),
 }
 
+/// Sections that are not displayed by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExtraSection {
+    /// Games, winrate, KDA and CS/M per role, with the main roles of the player
+    Roles,
+
+    /// Current streak, last play session and results of the last 20 games
+    Form,
+
+    /// LP over time, from the ranks recorded on each run
+    LpHistory,
+
+    /// Winrate, KDA and GD@15 of the most played champion against each lane opponent
+    Matchups,
+
+    /// Players often met in the team of the summoner, with the winrate together
+    Teammates,
+
+    /// Diversity of the champions played, and comfort picks compared to the other champions
+    Pool,
+
+    /// Rolling averages, trends and weekly aggregates of the performance, below the rank
+    Trends,
+
+    /// First blood involvement, death timings and map, and objective control, from the game timelines
+    Timeline,
+}
+
 /// Parses the champion name from the command line
-fn parse_champion(champion_name: &str) -> Result<Champion, Error> {
+pub fn parse_champion(champion_name: &str) -> Result<Champion, Error> {
     Champion::from_str(champion_name).context("Invalid champion name")
 }
 
 /// Parses the number of games to fetch for ranked statistics
-fn parse_number_of_parsed_games(s: &str) -> Result<i32, String> {
+pub fn parse_number_of_parsed_games(s: &str) -> Result<i32, String> {
     match s.parse() {
         Ok(games) => {
             if games > 0 {
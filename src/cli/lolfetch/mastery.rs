@@ -4,11 +4,16 @@ use clap::Parser;
 
 #[derive(Parser, Debug, Clone)]
 pub struct Mastery {
-    /// Number of games to fetch for statistics
-    #[clap(long, default_value = "10")]
-    pub games: i32,
-
     /// Number of mastery champions to display
     #[clap(long, default_value = "10")]
     pub mastery_champions: i32,
+
+    /// Number of recent games to fetch for the winrates on the mastery champions,
+    /// only the cached games are used with 0
+    #[clap(long, default_value = "0", value_parser = clap::value_parser!(i32).range(0..))]
+    pub games: i32,
+
+    /// Number of recent games on the displayed mastery champions to display, none by default
+    #[clap(long, value_parser = clap::value_parser!(i32).range(1..))]
+    pub recent_games: Option<i32>,
 }
@@ -1,6 +1,7 @@
 //! Ranked display options
 
-use super::parse_number_of_parsed_games;
+use super::{parse_number_of_parsed_games, ExtraSection};
+use crate::{api::account::RiotId, models::matches::TeamPosition};
 use clap::Parser;
 
 #[derive(Parser, Debug, Clone)]
@@ -16,4 +17,20 @@ pub struct Ranked {
     /// Number of recent matches to display
     #[clap(long, default_value = "5")]
     pub recent_matches: i32,
+
+    /// Only uses the games played in this role for the match history and champion stats
+    #[clap(long)]
+    pub role: Option<TeamPosition>,
+
+    /// Only uses the games played with this teammate (e.g. abc#1234), counted in `--games`
+    #[clap(long, value_parser = RiotId::from_str)]
+    pub duo: Option<RiotId>,
+
+    /// Number of games of the rolling averages used for the trends
+    #[clap(long, default_value = "5", value_parser = clap::value_parser!(u16).range(1..))]
+    pub rolling_window: u16,
+
+    /// Additional sections to display, separated by commas
+    #[clap(long, value_delimiter = ',')]
+    pub extra_sections: Vec<ExtraSection>,
 }
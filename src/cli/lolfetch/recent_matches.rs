@@ -1,5 +1,6 @@
 //! Recent matches display options
 
+use crate::{api::account::RiotId, models::matches::TeamPosition};
 use clap::Parser;
 
 #[derive(Parser, Debug, Clone)]
@@ -7,4 +8,12 @@ pub struct RecentMatches {
     /// Number of games to fetch and display
     #[clap(long, default_value = "5")]
     pub recent_matches: i32,
+
+    /// Only displays the games played in this role
+    #[clap(long)]
+    pub role: Option<TeamPosition>,
+
+    /// Only displays the games played with this teammate (e.g. abc#1234)
+    #[clap(long, value_parser = RiotId::from_str)]
+    pub duo: Option<RiotId>,
 }
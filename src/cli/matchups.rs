@@ -0,0 +1,25 @@
+//! Matchups CLI module.
+
+use super::{lolfetch::parse_champion, SummonerConfig};
+use clap::Parser;
+use riven::consts::Champion;
+
+/// CLI matchups arguments
+#[derive(Parser, Debug)]
+pub struct Matchups {
+    /// Summoner information
+    #[command(flatten)]
+    pub summoner: SummonerConfig,
+
+    /// Champion played by the summoner
+    #[clap(long, value_parser = parse_champion)]
+    pub champion: Champion,
+
+    /// Width of the output, defaults to the width of the terminal
+    #[clap(long)]
+    pub width: Option<usize>,
+
+    /// Color theme, see `lolfetch display --help`
+    #[clap(long)]
+    pub theme: Option<String>,
+}
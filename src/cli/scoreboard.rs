@@ -0,0 +1,31 @@
+//! Scoreboard CLI module.
+
+use super::{lolfetch::parse_number_of_parsed_games, SummonerConfig};
+use clap::Parser;
+
+/// CLI scoreboard arguments
+#[derive(Parser, Debug)]
+pub struct Scoreboard {
+    /// Summoner information, their row and their gold difference are highlighted
+    #[command(flatten)]
+    pub summoner: SummonerConfig,
+
+    /// ID of the match (e.g. EUW1_1234567890), the last game of the summoner if not given
+    pub match_id: Option<String>,
+
+    /// Show the scoreboards of the last N games of the summoner instead
+    #[clap(long, value_name = "N", conflicts_with = "match_id", value_parser = parse_number_of_parsed_games)]
+    pub last: Option<i32>,
+
+    /// Width of the output, defaults to the width of the terminal
+    #[clap(long)]
+    pub width: Option<usize>,
+
+    /// Color theme, see `lolfetch display --help`
+    #[clap(long)]
+    pub theme: Option<String>,
+
+    /// Do not save the fetched games to the cache
+    #[clap(long)]
+    pub no_save: bool,
+}
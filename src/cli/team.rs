@@ -0,0 +1,28 @@
+//! Team CLI module.
+
+use super::lolfetch::parse_number_of_parsed_games;
+use clap::Parser;
+
+/// CLI team arguments
+#[derive(Parser, Debug)]
+pub struct Team {
+    /// Name of the roster of the configuration file, optional if there is only one
+    #[clap(long)]
+    pub roster: Option<String>,
+
+    /// Number of recent ranked solo games of each player
+    #[clap(long, default_value = "10", value_parser = parse_number_of_parsed_games)]
+    pub games: i32,
+
+    /// Width of the output, defaults to the width of the terminal
+    #[clap(long)]
+    pub width: Option<usize>,
+
+    /// Color theme, see `lolfetch display --help`
+    #[clap(long)]
+    pub theme: Option<String>,
+
+    /// Do not save the fetched games to the cache
+    #[clap(long)]
+    pub no_save: bool,
+}
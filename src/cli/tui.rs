@@ -0,0 +1,16 @@
+//! TUI CLI module.
+
+use super::SummonerConfig;
+use clap::Parser;
+
+/// CLI TUI arguments
+#[derive(Parser, Debug)]
+pub struct Tui {
+    /// Summoner information
+    #[command(flatten)]
+    pub summoner: SummonerConfig,
+
+    /// Color theme, see `lolfetch display --help`
+    #[clap(long)]
+    pub theme: Option<String>,
+}
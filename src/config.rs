@@ -8,9 +8,25 @@ use crate::{
         self,
         lolfetch::{DisplayConfig, ImageSource, InfoKind},
     },
+    data::{
+        champion_stats::RecentChampionInfo, live_game::LiveGame, mastery::Mastery,
+        match_history::MatchHistory, matchups::Matchups, role_stats::RoleStats,
+        scoreboard::Scoreboard, team::Team, teammates::FrequentTeammates,
+    },
+    display::{
+        export::ExportFormat,
+        layout::LayoutOptions,
+        template::{Template, TemplateError},
+        theme::Theme,
+    },
 };
 use anyhow::{Context, Result};
+use clap::ValueEnum;
+use file::{FileConfig, TemplatesFile};
 use riven::consts::{Champion, PlatformRoute};
+use std::{path::PathBuf, time::Duration};
+
+pub mod file;
 
 /// Configuration of the application
 #[derive(Debug, Clone)]
@@ -27,6 +43,21 @@ pub struct Config {
     /// Type of image to display
     pub image: Image,
 
+    /// Arrangement of the image and the information
+    pub layout: LayoutOptions,
+
+    /// Colors used to render the sections
+    pub theme: Theme,
+
+    /// Row templates of the sections
+    pub templates: Templates,
+
+    /// File the output is exported to, instead of being printed
+    pub export: Option<Export>,
+
+    /// Interval between two refreshes of the output, `None` to display it once
+    pub watch: Option<Duration>,
+
     /// Display mode
     pub mode: InfoKind,
 
@@ -35,12 +66,33 @@ pub struct Config {
 }
 
 impl Config {
-    pub fn from_cli(value: cli::lolfetch::Lolfetch) -> Result<Self> {
+    pub fn from_cli(value: cli::lolfetch::Lolfetch, file: &FileConfig) -> Result<Self> {
         Ok(Self {
             account: Account {
                 riot_id: value.summoner.riot_id,
                 server: value.summoner.server.into(),
             },
+            export: value
+                .display_config
+                .export
+                .clone()
+                .map(|path| {
+                    let format = match value.display_config.export_format {
+                        Some(format) => format,
+                        None => ExportFormat::from_path(&path)?,
+                    };
+                    Ok::<_, anyhow::Error>(Export { path, format })
+                })
+                .transpose()?,
+            watch: value.display_config.watch.map(Duration::from_secs),
+            layout: LayoutOptions {
+                kind: value.display_config.layout,
+                image_position: value.display_config.image_position,
+                vertical_align: value.display_config.vertical_align,
+                width: value.display_config.width,
+            },
+            theme: load_theme(value.display_config.theme.as_deref(), file)?,
+            templates: Templates::from_file(&file.templates).context("Invalid template")?,
             image: Self::parse_image_config(value.display_config)
                 .context("Failed to parse image")?,
             mode: value.info_config,
@@ -67,6 +119,79 @@ impl Config {
     }
 }
 
+/// Export destination of the output
+#[derive(Debug, Clone)]
+pub struct Export {
+    pub path: PathBuf,
+    pub format: ExportFormat,
+}
+
+/// Row templates of the sections
+#[derive(Debug, Clone)]
+pub struct Templates {
+    pub match_history: Template,
+    pub champion_stats: Template,
+    pub mastery: Template,
+    pub role_stats: Template,
+    pub matchups: Template,
+    pub teammates: Template,
+    pub team: Template,
+    pub live_game: Template,
+    pub scoreboard: Template,
+}
+
+impl Templates {
+    pub fn from_file(file: &TemplatesFile) -> Result<Self, TemplateError> {
+        let parse = |template: &Option<String>, default: &str, fields: &[&str]| {
+            let template = Template::parse(template.as_deref().unwrap_or(default))?;
+            template.validate(fields)?;
+            Ok::<_, TemplateError>(template)
+        };
+
+        Ok(Self {
+            match_history: parse(
+                &file.match_history,
+                MatchHistory::DEFAULT_TEMPLATE,
+                MatchHistory::FIELDS,
+            )?,
+            champion_stats: parse(
+                &file.champion_stats,
+                RecentChampionInfo::DEFAULT_TEMPLATE,
+                RecentChampionInfo::FIELDS,
+            )?,
+            mastery: parse(&file.mastery, Mastery::DEFAULT_TEMPLATE, Mastery::FIELDS)?,
+            role_stats: parse(
+                &file.role_stats,
+                RoleStats::DEFAULT_TEMPLATE,
+                RoleStats::FIELDS,
+            )?,
+            matchups: parse(&file.matchups, Matchups::DEFAULT_TEMPLATE, Matchups::FIELDS)?,
+            teammates: parse(
+                &file.teammates,
+                FrequentTeammates::DEFAULT_TEMPLATE,
+                FrequentTeammates::FIELDS,
+            )?,
+            team: parse(&file.team, Team::DEFAULT_TEMPLATE, Team::FIELDS)?,
+            live_game: parse(
+                &file.live_game,
+                LiveGame::DEFAULT_TEMPLATE,
+                LiveGame::FIELDS,
+            )?,
+            scoreboard: parse(
+                &file.scoreboard,
+                Scoreboard::DEFAULT_TEMPLATE,
+                Scoreboard::FIELDS,
+            )?,
+        })
+    }
+}
+
+/// Loads the theme given on the command line, or the one of the configuration file, or `default`.
+pub fn load_theme(name: Option<&str>, file: &FileConfig) -> Result<Theme> {
+    let name = name.or(file.theme.as_deref()).unwrap_or("default");
+    Theme::load(name).context("Failed to load theme")
+}
+
 /// Summoner information
 #[derive(Debug, Clone)]
 pub struct Account {
@@ -86,6 +211,59 @@ impl From<cli::SummonerConfig> for Account {
     }
 }
 
+/// Group of players of the configuration file
+#[derive(Debug, Clone)]
+pub struct Roster {
+    pub name: String,
+    pub members: Vec<Account>,
+}
+
+impl Roster {
+    /// Loads the roster named `name` from the configuration file.
+    /// The name can be omitted when the file has a single roster.
+    pub fn from_file(name: Option<&str>, file: &FileConfig) -> Result<Self> {
+        let (name, members) = match name {
+            Some(name) => file
+                .rosters
+                .get_key_value(name)
+                .with_context(|| format!("Roster {name} not found in the configuration file"))?,
+            None => {
+                let mut rosters = file.rosters.iter();
+                match (rosters.next(), rosters.next()) {
+                    (Some(roster), None) => roster,
+                    (None, _) => anyhow::bail!("No roster in the configuration file"),
+                    (Some(_), Some(_)) => anyhow::bail!(
+                        "Several rosters in the configuration file, choose one with --roster ({})",
+                        file.rosters.keys().cloned().collect::<Vec<_>>().join(", ")
+                    ),
+                }
+            }
+        };
+
+        let members = members
+            .iter()
+            .map(|member| {
+                let server = cli::LeagueServer::from_str(&member.server, true)
+                    .map_err(|_| anyhow::anyhow!("Invalid server {}", member.server))?;
+                Ok(Account {
+                    riot_id: RiotId::from_str(&member.riot_id)
+                        .with_context(|| format!("Invalid Riot ID {}", member.riot_id))?,
+                    server: server.into(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+            .with_context(|| format!("Invalid roster {name}"))?;
+        if members.is_empty() {
+            anyhow::bail!("Roster {name} has no players");
+        }
+
+        Ok(Self {
+            name: name.clone(),
+            members,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Image {
     /// Default image display, based on the display type
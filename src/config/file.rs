@@ -0,0 +1,83 @@
+//! Configuration file of the application.
+//!
+//! The file is written in TOML and is read from `lolfetch/config.toml` in the configuration
+//! directory, unless another path is given with `--config`. Every setting is optional.
+
+use serde::Deserialize;
+use std::{collections::BTreeMap, fs, path::Path};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FileConfigError {
+    #[error("Configuration file {0} not found")]
+    NotFound(String),
+
+    #[error("Failed to read configuration file: {0}")]
+    Read(#[from] std::io::Error),
+
+    #[error("Failed to parse configuration file: {0}")]
+    Parse(#[from] toml::de::Error),
+}
+
+/// Content of the configuration file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FileConfig {
+    /// Theme used when none is given on the command line.
+    pub theme: Option<String>,
+
+    /// Row templates of the sections.
+    pub templates: TemplatesFile,
+
+    /// Groups of players shown by `lolfetch team`, by name.
+    pub rosters: BTreeMap<String, Vec<RosterMember>>,
+}
+
+/// Player of a roster.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RosterMember {
+    /// Riot ID of the player (e.g. abc#1234)
+    pub riot_id: String,
+
+    /// Server the account is registered on, as given to `--server` (e.g. EUW)
+    pub server: String,
+}
+
+/// Row templates of the sections, the default template of a section is used if not set.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TemplatesFile {
+    pub match_history: Option<String>,
+    pub champion_stats: Option<String>,
+    pub mastery: Option<String>,
+    pub role_stats: Option<String>,
+    pub matchups: Option<String>,
+    pub teammates: Option<String>,
+    pub team: Option<String>,
+    pub live_game: Option<String>,
+    pub scoreboard: Option<String>,
+}
+
+impl FileConfig {
+    /// Loads the configuration file from `path`, or from the default location if not given.
+    /// A missing file at the default location is not an error.
+    pub fn load(path: Option<&Path>) -> Result<Self, FileConfigError> {
+        let path = match path {
+            Some(path) if !path.is_file() => {
+                return Err(FileConfigError::NotFound(path.display().to_string()))
+            }
+            Some(path) => path.to_path_buf(),
+            None => match dirs::config_dir()
+                .map(|dir| dir.join("lolfetch").join("config.toml"))
+                .filter(|path| path.is_file())
+            {
+                Some(path) => path,
+                None => return Ok(Self::default()),
+            },
+        };
+
+        info!("Loading configuration file {}", path.display());
+        Ok(toml::from_str(&fs::read_to_string(path)?)?)
+    }
+}
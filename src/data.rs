@@ -1,22 +1,51 @@
 //! This module handles gathering information to display to the user.
 
 use crate::{
-    api::Data as ApiData,
-    cli::lolfetch::InfoKind,
+    api::{account::RiotId, tooling::static_data, Data as ApiData},
+    cli::lolfetch::{ExtraSection, InfoKind},
     config::Config,
     display::{DisplayableSectionKind, IMAGE_HEIGHT, IMAGE_WIDTH},
+    models::{
+        matches::{MatchId, MatchInfo, MatchPlayerInfo, ParticipantGetter, TeamPosition},
+        ranked::{self, RankSnapshot},
+        teammates::played_with,
+        trends::GameSample,
+    },
 };
+use champion_pool::ChampionPool;
 use champion_stats::RecentChampionInfo;
 use lolfetch_ascii::ColoredArt;
+use lp_history::LpHistory;
 use mastery::Mastery;
 use match_history::MatchHistory;
+use matchups::Matchups;
+use recent_form::RecentForm;
+use riven::{consts::Queue, models::summoner_v4};
+use role_stats::RoleStats;
+use std::collections::HashMap;
 use summoner::Summoner;
+use teammates::FrequentTeammates;
 use thiserror::Error;
+use timeline::TimelineAnalysis;
 
+pub mod champion_pool;
 pub mod champion_stats;
+pub mod comparison;
+pub mod fields;
+pub mod live_client;
+pub mod live_game;
+pub mod lp_history;
 pub mod mastery;
 pub mod match_history;
+pub mod matchups;
+pub mod recent_form;
+pub mod refresh;
+pub mod role_stats;
+pub mod scoreboard;
 pub mod summoner;
+pub mod team;
+pub mod teammates;
+pub mod timeline;
 
 #[derive(Error, Debug)]
 pub enum ProcessingError {
@@ -37,39 +66,166 @@ impl ApplicationData {
         info!("Processing fetched data");
 
         let mut sections = Vec::new();
+        let lp_changes = data
+            .matches
+            .as_deref()
+            .map(|matches| lp_changes(&data.rank_history, matches))
+            .unwrap_or_default();
         match &config.mode {
             InfoKind::Ranked(ranked) => {
                 // Name + Ranked champion stats + Recent Matches
-                let ranked_summoner = Summoner::new(&config.account.riot_id, data.ranked);
+                let mut ranked_summoner = Summoner::new(&config.account.riot_id, data.ranked);
 
-                let Some(mut matches) = data.matches else {
+                let Some(matches) = data.matches else {
                     return Err(ProcessingError::IncorrectData(
                         "Matches should be fetched".to_string(),
                     ));
                 };
+                // The games of other queues fetched by the same run are returned with the cached ones
+                let matches = ranked_solo(matches);
+                // Not limited to the games with the duo, to compare the teammates
+                let teammates = ranked
+                    .extra_sections
+                    .contains(&ExtraSection::Teammates)
+                    .then(|| {
+                        FrequentTeammates::new(
+                            &matches,
+                            &data.summoner,
+                            &config.templates.teammates,
+                        )
+                    });
+                let mut matches = filter_duo(matches, &data.summoner, ranked.duo.as_ref());
+                // Not limited to the games of the statistics, to go further back when possible
+                let recent_form = ranked
+                    .extra_sections
+                    .contains(&ExtraSection::Form)
+                    .then(|| RecentForm::new(&matches, &data.summoner));
+                if ranked.extra_sections.contains(&ExtraSection::Trends) {
+                    ranked_summoner = ranked_summoner.with_trends(
+                        GameSample::from_matches(&matches, &data.summoner),
+                        usize::from(ranked.rolling_window),
+                    );
+                }
+                let matchups = if ranked.extra_sections.contains(&ExtraSection::Matchups) {
+                    Matchups::played_champions(&matches, &data.summoner)
+                        .first()
+                        .map(|&champion| {
+                            Matchups::new(
+                                &matches,
+                                &data.summoner,
+                                champion,
+                                &config.templates.matchups,
+                            )
+                        })
+                } else {
+                    None
+                };
+
                 matches.truncate(ranked.games as usize);
 
-                let champions =
-                    RecentChampionInfo::new(&matches, &data.summoner, ranked.top_champions);
+                let role_stats = ranked
+                    .extra_sections
+                    .contains(&ExtraSection::Roles)
+                    .then(|| {
+                        RoleStats::new(&matches, &data.summoner, &config.templates.role_stats)
+                    });
+
+                let matches = filter_role(matches, &data.summoner, ranked.role);
 
-                let match_history =
-                    MatchHistory::new(&matches, &data.summoner, ranked.recent_matches);
+                let champion_pool = ranked
+                    .extra_sections
+                    .contains(&ExtraSection::Pool)
+                    .then(|| ChampionPool::new(&matches, &data.summoner));
+
+                let names = static_data::get_static_names().await;
+                let timeline = ranked
+                    .extra_sections
+                    .contains(&ExtraSection::Timeline)
+                    .then(|| TimelineAnalysis::new(&matches, &data.summoner, names));
+                let champions = RecentChampionInfo::new(
+                    &matches,
+                    &data.summoner,
+                    ranked.top_champions,
+                    usize::from(ranked.rolling_window),
+                    names,
+                    &config.templates.champion_stats,
+                );
+
+                let match_history = MatchHistory::new(
+                    &matches,
+                    &data.summoner,
+                    ranked.recent_matches,
+                    &lp_changes,
+                    names,
+                    &config.templates.match_history,
+                );
 
                 sections.push(DisplayableSectionKind::Summoner(ranked_summoner));
                 sections.push(DisplayableSectionKind::MatchHistory(match_history));
                 sections.push(DisplayableSectionKind::RecentChampionInfo(champions));
+                if let Some(role_stats) = role_stats {
+                    sections.push(DisplayableSectionKind::RoleStats(role_stats));
+                }
+                if let Some(recent_form) = recent_form {
+                    sections.push(DisplayableSectionKind::RecentForm(recent_form));
+                }
+                if ranked.extra_sections.contains(&ExtraSection::LpHistory) {
+                    sections.push(DisplayableSectionKind::LpHistory(LpHistory::new(
+                        &data.rank_history,
+                    )));
+                }
+                if let Some(matchups) = matchups {
+                    sections.push(DisplayableSectionKind::Matchups(matchups));
+                }
+                if let Some(teammates) = teammates {
+                    sections.push(DisplayableSectionKind::FrequentTeammates(teammates));
+                }
+                if let Some(champion_pool) = champion_pool {
+                    sections.push(DisplayableSectionKind::ChampionPool(champion_pool));
+                }
+                if let Some(timeline) = timeline {
+                    sections.push(DisplayableSectionKind::TimelineAnalysis(timeline));
+                }
             }
             InfoKind::Mastery(mastery) => {
                 // Name + Masteries
 
                 let summoner = Summoner::new(&config.account.riot_id, None);
-                let mastery = Mastery::new(
+                let matches = data.matches.unwrap_or_default();
+                let masteries = Mastery::new(
                     data.masteries.expect("Masteries should be fetched"),
+                    &matches,
+                    &data.summoner,
                     mastery.mastery_champions,
+                    &config.templates.mastery,
                 );
+                let recent_games = match mastery.recent_games {
+                    Some(recent_games) => {
+                        let champions = masteries.champions();
+                        let games = matches
+                            .into_iter()
+                            .filter(|game| {
+                                MatchPlayerInfo::from_match_info(game, &data.summoner)
+                                    .is_ok_and(|player| champions.contains(&player.champion))
+                            })
+                            .collect::<Vec<_>>();
+                        Some(MatchHistory::new(
+                            &games,
+                            &data.summoner,
+                            recent_games,
+                            &lp_changes,
+                            static_data::get_static_names().await,
+                            &config.templates.match_history,
+                        ))
+                    }
+                    None => None,
+                };
 
                 sections.push(DisplayableSectionKind::Summoner(summoner));
-                sections.push(DisplayableSectionKind::Mastery(mastery));
+                sections.push(DisplayableSectionKind::Mastery(masteries));
+                if let Some(recent_games) = recent_games {
+                    sections.push(DisplayableSectionKind::MatchHistory(recent_games));
+                }
             }
             InfoKind::RecentMatches(recent) => {
                 // Name + Recent Matches
@@ -80,9 +236,18 @@ impl ApplicationData {
                         "Matches should be fetched".to_string(),
                     ));
                 };
+                let matches = ranked_solo(matches);
+                let matches = filter_duo(matches, &data.summoner, recent.duo.as_ref());
+                let matches = filter_role(matches, &data.summoner, recent.role);
 
-                let match_history =
-                    MatchHistory::new(&matches, &data.summoner, recent.recent_matches);
+                let match_history = MatchHistory::new(
+                    &matches,
+                    &data.summoner,
+                    recent.recent_matches,
+                    &lp_changes,
+                    static_data::get_static_names().await,
+                    &config.templates.match_history,
+                );
 
                 sections.push(DisplayableSectionKind::Summoner(ranked_summoner));
                 sections.push(DisplayableSectionKind::MatchHistory(match_history));
@@ -97,3 +262,60 @@ impl ApplicationData {
         Ok(Self { image, sections })
     }
 }
+
+/// Keeps the matches in which the summoner played `role`, or all of them if no role is given.
+fn filter_role(
+    matches: Vec<MatchInfo>,
+    summoner: &summoner_v4::Summoner,
+    role: Option<TeamPosition>,
+) -> Vec<MatchInfo> {
+    let Some(role) = role else {
+        return matches;
+    };
+
+    matches
+        .into_iter()
+        .filter(|game| {
+            game.info
+                .get_participant(summoner)
+                .is_ok_and(|participant| {
+                    TeamPosition::try_from(participant.team_position.clone()).ok() == Some(role)
+                })
+        })
+        .collect()
+}
+
+/// Keeps the ranked solo matches.
+fn ranked_solo(matches: Vec<MatchInfo>) -> Vec<MatchInfo> {
+    matches
+        .into_iter()
+        .filter(|game| game.info.queue_id == Queue::SUMMONERS_RIFT_5V5_RANKED_SOLO)
+        .collect()
+}
+
+/// Keeps the matches played with `duo` in the team of the summoner, or all of them if no duo is given.
+fn filter_duo(
+    matches: Vec<MatchInfo>,
+    summoner: &summoner_v4::Summoner,
+    duo: Option<&RiotId>,
+) -> Vec<MatchInfo> {
+    let Some(duo) = duo else {
+        return matches;
+    };
+
+    matches
+        .into_iter()
+        .filter(|game| played_with(game, summoner, duo))
+        .collect()
+}
+
+/// Returns the LP won or lost in the ranked solo games, from the recorded ranks.
+fn lp_changes(rank_history: &[RankSnapshot], matches: &[MatchInfo]) -> HashMap<MatchId, i32> {
+    let games = matches
+        .iter()
+        .filter(|game| game.info.queue_id == Queue::SUMMONERS_RIFT_5V5_RANKED_SOLO)
+        .map(|game| (game.id.clone(), game.end_timestamp()))
+        .collect::<Vec<_>>();
+
+    ranked::lp_changes(rank_history, &games)
+}
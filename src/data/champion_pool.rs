@@ -0,0 +1,139 @@
+//! Diversity of the champion pool, and how the comfort picks compare to the other champions.
+
+use crate::{
+    data::fields,
+    display::{theme, DisplayableSection},
+    models::{
+        champion_pool::{PoolKind, PoolSummary, COMFORT_PICKS},
+        champion_stats::GameStats,
+        matches::{MatchInfo, MatchPlayerInfo},
+    },
+};
+use lolfetch_color::ColoredString;
+use riven::{consts::Champion, models::summoner_v4::Summoner};
+use std::collections::HashMap;
+
+/// Winrate difference between the comfort picks and the other champions from which the pool should be narrowed.
+const NARROW_WINRATE_GAP: f32 = 0.05;
+
+pub struct ChampionPool {
+    summary: Option<PoolSummary>,
+    comfort_picks: Vec<Champion>,
+    comfort_stats: GameStats,
+    off_pick_stats: GameStats,
+    games_processed: usize,
+}
+
+impl ChampionPool {
+    pub fn new(matches: &[MatchInfo], summoner: &Summoner) -> Self {
+        let match_infos = matches
+            .iter()
+            .map(|game| {
+                MatchPlayerInfo::from_match_info(game, summoner)
+                    .expect("Failed to get match player info")
+            })
+            .collect::<Vec<_>>();
+
+        let mut games = HashMap::new();
+        for match_info in &match_infos {
+            *games.entry(match_info.champion).or_insert(0) += 1;
+        }
+        let mut games = games.into_iter().collect::<Vec<_>>();
+        games.sort_by_key(|(champion, games)| (std::cmp::Reverse(*games), champion.name()));
+
+        let comfort_picks = games
+            .iter()
+            .take(COMFORT_PICKS)
+            .map(|(champion, _)| *champion)
+            .collect::<Vec<_>>();
+        let mut comfort_stats = GameStats::new();
+        let mut off_pick_stats = GameStats::new();
+        for match_info in &match_infos {
+            if comfort_picks.contains(&match_info.champion) {
+                comfort_stats.add_game(match_info);
+            } else {
+                off_pick_stats.add_game(match_info);
+            }
+        }
+
+        Self {
+            summary: PoolSummary::new(&games.iter().map(|(_, games)| *games).collect::<Vec<_>>()),
+            comfort_picks,
+            comfort_stats,
+            off_pick_stats,
+            games_processed: matches.len(),
+        }
+    }
+}
+
+/// Returns the winrate, KDA and CS/M of the games, e.g. `55% WR - 3.1 KDA - 7.2 CS/M`.
+fn stats_text(stats: &GameStats) -> String {
+    format!(
+        "{:.0}% WR - {} - {:.1} CS/M",
+        stats.winrate() * 100.0,
+        fields::kda_text(stats.kda()),
+        stats.cspm()
+    )
+}
+
+impl DisplayableSection for ChampionPool {
+    fn header(&self) -> Option<String> {
+        Some(format!(
+            "Champion Pool (last {} games)",
+            self.games_processed
+        ))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+
+        let Some(summary) = self.summary else {
+            return vec![ColoredString::from_unformatted_str("No recent games")];
+        };
+
+        let mut body = vec![ColoredString::from_unformatted_str(&format!(
+            "{} champions - Top 1: {:.0}% - Top {}: {:.0}% - Diversity: {:.1}",
+            summary.unique_champions,
+            summary.top_share * 100.0,
+            COMFORT_PICKS,
+            summary.comfort_share * 100.0,
+            summary.diversity
+        ))];
+
+        let mut kind = ColoredString::from_unformatted_str("Pool: ");
+        kind.push_str(&summary.kind.to_string(), Some(theme.accent), None);
+        body.push(kind);
+
+        let comfort_picks = self
+            .comfort_picks
+            .iter()
+            .map(|champion| champion.name().unwrap_or("Unknown"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        body.push(ColoredString::from_unformatted_str(&format!(
+            "Comfort ({comfort_picks}): {}",
+            stats_text(&self.comfort_stats)
+        )));
+
+        if self.off_pick_stats.total_games() > 0 {
+            body.push(ColoredString::from_unformatted_str(&format!(
+                "Off-picks ({} games): {}",
+                self.off_pick_stats.total_games(),
+                stats_text(&self.off_pick_stats)
+            )));
+
+            if summary.kind == PoolKind::Wide
+                && self.comfort_stats.winrate() - self.off_pick_stats.winrate()
+                    >= NARROW_WINRATE_GAP
+            {
+                body.push(ColoredString::from_str(
+                    "Your comfort picks win more, consider narrowing your pool",
+                    Some(theme.negative),
+                    None,
+                ));
+            }
+        }
+
+        body
+    }
+}
@@ -1,59 +1,136 @@
 use crate::{
-    display::DisplayableSection,
+    api::tooling::static_data::StaticNames,
+    data::fields,
+    display::{
+        template::{Field, Fields, Template},
+        theme,
+        utils::widgets,
+        DisplayableSection,
+    },
     models::{
         champion_stats::GameStats,
+        loadout::{Choices, RunePage},
         matches::{MatchInfo, MatchPlayerInfo},
+        trends::{self, GameSample, Metric, Trend},
     },
 };
 use lolfetch_color::ColoredString;
 use riven::{consts::Champion, models::summoner_v4::Summoner};
 use std::collections::HashMap;
 
+/// Most expensive items of a game making up its core build.
+const CORE_BUILD_ITEMS: usize = 3;
+
 pub struct ChampionStats {
     champion: Champion,
     stats: GameStats,
+    /// Games from the oldest to the most recent, for the rolling averages.
+    samples: Vec<GameSample>,
+    /// Core items of each game, see [`StaticNames::core_items`].
+    builds: Choices<Vec<i32>>,
+    rune_pages: Choices<RunePage>,
 }
 
 pub struct RecentChampionInfo {
     stats: Vec<ChampionStats>,
     games_processed: usize,
+    /// Number of games of the rolling averages.
+    window: usize,
+    names: &'static StaticNames,
+    template: Template,
 }
 
 impl RecentChampionInfo {
-    pub fn new(matches: &[MatchInfo], summoner: &Summoner, max_champs: i32) -> Self {
+    pub const DEFAULT_TEMPLATE: &'static str =
+        "{champion:<} - {winrate:>3}% WR - {kda_ratio} - {cs_per_min} CS/M - {games} Played[ - GD@15: {gd@15:+}]";
+
+    /// Fields available in the row template.
+    pub const FIELDS: &'static [&'static str] = &[
+        "champion",
+        "winrate",
+        "winrate_bar",
+        "wins",
+        "losses",
+        "games",
+        "share",
+        "kda_ratio",
+        "kda_trend",
+        "winrate_trend",
+        "cs_per_min_trend",
+        "gd15_trend",
+        "trend",
+        "cs_per_min",
+        "kp",
+        "damage_share",
+        "dpm",
+        "gpm",
+        "vspm",
+        "control_wards",
+        "objective_participation",
+        "gd@<minute>",
+        "csd@<minute>",
+        "xpd@<minute>",
+        "lvld@<minute>",
+        "core_build",
+        "core_build_games",
+        "core_build_winrate",
+        "winning_build",
+        "runes",
+        "runes_games",
+        "runes_winrate",
+    ];
+
+    pub fn new(
+        matches: &[MatchInfo],
+        summoner: &Summoner,
+        max_champs: i32,
+        window: usize,
+        names: &'static StaticNames,
+        template: &Template,
+    ) -> Self {
         let mut stats = HashMap::new();
 
-        for game in matches {
+        // Matches are sorted from the most recent, go through them chronologically
+        for game in matches.iter().rev() {
             let match_info = MatchPlayerInfo::from_match_info(game, summoner)
                 .expect("Failed to get match player info");
-            let champion_stats = stats.entry(match_info.champion).or_insert(GameStats::new());
-            champion_stats.add_game(&match_info);
+            let champion_stats =
+                stats
+                    .entry(match_info.champion)
+                    .or_insert_with(|| ChampionStats {
+                        champion: match_info.champion,
+                        stats: GameStats::new(),
+                        samples: Vec::new(),
+                        builds: Choices::new(),
+                        rune_pages: Choices::new(),
+                    });
+            champion_stats.stats.add_game(&match_info);
+            champion_stats
+                .samples
+                .push(GameSample::new(game, &match_info));
+            let mut core_items = names.core_items(&match_info.loadout.items);
+            core_items.truncate(CORE_BUILD_ITEMS);
+            if !core_items.is_empty() {
+                champion_stats
+                    .builds
+                    .add(core_items, match_info.game_result);
+            }
+            if let Some(runes) = match_info.loadout.runes {
+                champion_stats.rune_pages.add(runes, match_info.game_result);
+            }
         }
 
-        let mut sorted = stats.into_iter().collect::<Vec<_>>();
-        sorted.sort_by(|a, b| b.1.total_games().cmp(&a.1.total_games()));
+        let mut sorted = stats.into_values().collect::<Vec<_>>();
+        sorted.sort_by_key(|stats| std::cmp::Reverse(stats.stats.total_games()));
 
         Self {
-            stats: sorted
-                .into_iter()
-                .take(max_champs as usize)
-                .map(|(champion, stats)| ChampionStats { champion, stats })
-                .collect(),
+            stats: sorted.into_iter().take(max_champs as usize).collect(),
             games_processed: matches.len(),
+            window,
+            names,
+            template: template.clone(),
         }
     }
-
-    pub fn max_champion_name_width(&self) -> Option<usize> {
-        self.stats
-            .iter()
-            .map(|s| {
-                s.champion
-                    .name()
-                    .expect("Failed to get champion name")
-                    .len()
-            })
-            .max()
-    }
 }
 
 impl DisplayableSection for RecentChampionInfo {
@@ -65,38 +142,89 @@ impl DisplayableSection for RecentChampionInfo {
     }
 
     fn body(&self) -> Vec<ColoredString> {
-        let mut body = Vec::new();
-
-        let mut stat_iter = self.stats.iter();
+        let theme = theme::current();
 
-        let max_width = self.max_champion_name_width().expect("No matches found");
-
-        for _ in 0..5 {
-            let Some(champion_stats) = stat_iter.next() else {
-                break;
-            };
-            let mut champion_body = ColoredString::new();
-            let kda_str = champion_stats
-                .stats
-                .kda()
-                .map_or_else(|| "PERFECT".to_string(), |kda| format!("{kda:.1} KDA"));
-
-            champion_body.push_unformatted_str(&format!(
-                "{:<width$} - {:3.0}% WR - {} - {:.1} CS/M - {} Played",
-                champion_stats
-                    .champion
-                    .name()
-                    .expect("Failed to get champion name"),
-                champion_stats.stats.winrate() * 100.0,
-                kda_str,
-                champion_stats.stats.cspm(),
-                champion_stats.stats.total_games(),
-                width = max_width
-            ));
-
-            body.push(champion_body);
-        }
+        let rows = self
+            .stats
+            .iter()
+            .map(|champion_stats| {
+                let stats = &champion_stats.stats;
+                let mut fields = Fields::from([
+                    (
+                        "champion",
+                        Field::text(
+                            champion_stats
+                                .champion
+                                .name()
+                                .expect("Failed to get champion name"),
+                        ),
+                    ),
+                    (
+                        "share",
+                        Field::text(percentage(stats.total_games(), self.games_processed as u32)),
+                    ),
+                ]);
+                fields::insert_results(&mut fields, stats.wins(), stats.losses());
+                fields::insert_kda_cs(&mut fields, stats);
+                let samples = &champion_stats.samples;
+                for (name, metric) in [
+                    ("winrate_trend", Metric::Winrate),
+                    ("kda_trend", Metric::Kda),
+                    ("cs_per_min_trend", Metric::CsPerMin),
+                    ("gd15_trend", Metric::Gd15),
+                ] {
+                    let rolling = trends::rolling_average(&metric.values(samples), self.window);
+                    if !rolling.is_empty() {
+                        fields.insert(
+                            name,
+                            Field::widget(widgets::sparkline(&rolling, Some(theme.accent))),
+                        );
+                    }
+                }
+                let rolling_winrate =
+                    trends::rolling_average(&Metric::Winrate.values(samples), self.window);
+                let trend = Trend::of(&rolling_winrate, Metric::Winrate);
+                fields.insert(
+                    "trend",
+                    Field::colored(
+                        trend.arrow().to_string(),
+                        match trend {
+                            Trend::Improving => Some(theme.positive),
+                            Trend::Declining => Some(theme.negative),
+                            Trend::Stable => None,
+                        },
+                    ),
+                );
+                if let Some((build, games, wins)) = champion_stats.builds.most_common() {
+                    fields.insert(
+                        "core_build",
+                        Field::text(fields::items_text(self.names, build)),
+                    );
+                    fields.insert("core_build_games", Field::text(games.to_string()));
+                    fields.insert("core_build_winrate", Field::text(percentage(wins, games)));
+                }
+                if let Some(build) = champion_stats.builds.most_wins() {
+                    fields.insert(
+                        "winning_build",
+                        Field::text(fields::items_text(self.names, build)),
+                    );
+                }
+                if let Some((&runes, games, wins)) = champion_stats.rune_pages.most_common() {
+                    fields.insert("runes", Field::text(fields::runes_text(self.names, runes)));
+                    fields.insert("runes_games", Field::text(games.to_string()));
+                    fields.insert("runes_winrate", Field::text(percentage(wins, games)));
+                }
+                fields::insert_performance(&mut fields, stats, true);
+                fields::insert_lane_diffs(&mut fields, stats.lane_diffs().averages(), true);
+                fields
+            })
+            .collect::<Vec<_>>();
 
-        body
+        self.template.render(&rows)
     }
 }
+
+/// Returns the share of the games, as a percentage without the `%` sign.
+fn percentage(part: u32, games: u32) -> String {
+    format!("{:.0}", f64::from(part) / f64::from(games) * 100.0)
+}
@@ -0,0 +1,288 @@
+//! Side by side comparison of two players.
+
+use crate::{
+    data::fields,
+    display::{
+        template::{Field, Fields, Template},
+        theme,
+        utils::colors::RankColorGetter,
+        DisplayableSection,
+    },
+    models::{champion_stats::GameStats, summary::PlayerSummary},
+};
+use lolfetch_color::ColoredString;
+use termcolor::Color;
+
+/// Layout of the rows, a label followed by the value of each player.
+const ROW_TEMPLATE: &str = "{label:<}   {first:<}   {second:<}";
+
+/// Number of roles and champions listed for each player.
+const MAX_LISTED: usize = 3;
+
+pub struct Comparison {
+    players: Box<[PlayerSummary; 2]>,
+}
+
+impl Comparison {
+    pub fn new(players: [PlayerSummary; 2]) -> Self {
+        Self {
+            players: Box::new(players),
+        }
+    }
+
+    /// Returns a row with a value for each player, the one with the highest score being highlighted.
+    fn row(label: &str, values: [String; 2], scores: [Option<f64>; 2]) -> Fields {
+        let theme = theme::current();
+        let best = best(scores);
+        let [first, second] = values;
+        let color = |player: usize| (best == Some(player)).then_some(theme.positive);
+
+        Fields::from([
+            ("label", Field::text(label)),
+            ("first", Field::colored(first, color(0))),
+            ("second", Field::colored(second, color(1))),
+        ])
+    }
+
+    /// Returns a row comparing a statistic of the recent games, higher being better.
+    fn stat_row(
+        label: &str,
+        players: &[PlayerSummary; 2],
+        stat: impl Fn(&GameStats) -> Option<f64>,
+        format: impl Fn(f64) -> String,
+    ) -> Fields {
+        let scores = [stat(&players[0].stats), stat(&players[1].stats)];
+        Self::row(
+            label,
+            scores.map(|score| score.map_or_else(|| "-".to_string(), &format)),
+            scores,
+        )
+    }
+}
+
+impl DisplayableSection for Comparison {
+    fn header(&self) -> Option<String> {
+        // Players may have fewer ranked games than requested
+        let [first, second] = self
+            .players
+            .each_ref()
+            .map(|player| player.stats.total_games());
+        let games = if first == second {
+            first.to_string()
+        } else {
+            format!("{first} and {second}")
+        };
+        Some(format!("Comparison (last {games} ranked games)"))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+        let template = Template::parse(ROW_TEMPLATE).expect("The comparison template is valid");
+        let players = &self.players;
+        let rank_color = |player: &PlayerSummary| -> Option<Color> {
+            player
+                .ranked
+                .as_ref()
+                .and_then(|ranked| ranked.tier.get_rank_color())
+        };
+
+        let mut rows = vec![Fields::from([
+            ("label", Field::text("")),
+            (
+                "first",
+                Field::colored(players[0].riot_id.to_string(), Some(theme.accent)),
+            ),
+            (
+                "second",
+                Field::colored(players[1].riot_id.to_string(), Some(theme.accent)),
+            ),
+        ])];
+
+        rows.push(Fields::from([
+            ("label", Field::text("Rank")),
+            (
+                "first",
+                Field::colored(
+                    players[0]
+                        .ranked
+                        .as_ref()
+                        .map_or_else(|| "Unranked".to_string(), ToString::to_string),
+                    rank_color(&players[0]),
+                ),
+            ),
+            (
+                "second",
+                Field::colored(
+                    players[1]
+                        .ranked
+                        .as_ref()
+                        .map_or_else(|| "Unranked".to_string(), ToString::to_string),
+                    rank_color(&players[1]),
+                ),
+            ),
+        ]));
+
+        let season_winrates = players.each_ref().map(|player| {
+            player
+                .ranked
+                .as_ref()
+                .and_then(|ranked| ranked.get_winrate().map(|winrate| (winrate, ranked)))
+        });
+        rows.push(Self::row(
+            "Season WR",
+            season_winrates.map(|winrate| {
+                winrate.map_or_else(
+                    || "-".to_string(),
+                    |(winrate, ranked)| {
+                        format!(
+                            "{:.0}% ({} games)",
+                            winrate * 100.0,
+                            ranked.wins + ranked.losses
+                        )
+                    },
+                )
+            }),
+            season_winrates.map(|winrate| winrate.map(|(winrate, _)| f64::from(winrate))),
+        ));
+
+        rows.push(Self::row(
+            "Recent WR",
+            players.each_ref().map(|player| {
+                if player.stats.total_games() == 0 {
+                    "-".to_string()
+                } else {
+                    format!(
+                        "{:.0}% ({}W/{}L)",
+                        player.stats.winrate() * 100.0,
+                        player.stats.wins(),
+                        player.stats.losses()
+                    )
+                }
+            }),
+            players.each_ref().map(|player| {
+                (player.stats.total_games() > 0).then(|| f64::from(player.stats.winrate()))
+            }),
+        ));
+
+        let played = |stats: &GameStats| stats.total_games() > 0;
+        rows.push(Self::stat_row(
+            "KDA",
+            players,
+            |stats| played(stats).then(|| stats.kda().unwrap_or(f64::INFINITY)),
+            |kda| fields::kda_ratio(kda.is_finite().then_some(kda)),
+        ));
+        rows.push(Self::stat_row(
+            "CS/M",
+            players,
+            |stats| played(stats).then(|| stats.cspm()),
+            |cspm| format!("{cspm:.1}"),
+        ));
+        rows.push(Self::stat_row(
+            "GD@15",
+            players,
+            |stats| stats.lane_diffs().average(15).map(|diff| diff.gold),
+            |gold| format!("{gold:+.0}"),
+        ));
+        rows.push(Self::stat_row(
+            "KP",
+            players,
+            GameStats::kill_participation,
+            |kp| format!("{:.0}%", kp * 100.0),
+        ));
+
+        let listed = |values: Vec<String>| {
+            if values.is_empty() {
+                "-".to_string()
+            } else {
+                values.join(", ")
+            }
+        };
+        rows.push(Self::row(
+            "Roles",
+            players.each_ref().map(|player| {
+                listed(
+                    player
+                        .role_shares()
+                        .take(MAX_LISTED)
+                        .map(|(role, share)| format!("{role} {:.0}%", share * 100.0))
+                        .collect(),
+                )
+            }),
+            [None, None],
+        ));
+        rows.push(Self::row(
+            "Champions",
+            players.each_ref().map(|player| {
+                listed(
+                    player
+                        .champions
+                        .iter()
+                        .take(MAX_LISTED)
+                        .map(|(champion, _)| champion.name().unwrap_or("Unknown").to_string())
+                        .collect(),
+                )
+            }),
+            [None, None],
+        ));
+
+        // Champions played by both players, from the most played in total
+        let mut shared = players[0]
+            .champions
+            .iter()
+            .filter_map(|(champion, first)| {
+                players[1]
+                    .champion(*champion)
+                    .map(|second| (*champion, [first, second]))
+            })
+            .collect::<Vec<_>>();
+        shared.sort_by_key(|(_, stats)| {
+            std::cmp::Reverse(stats[0].total_games() + stats[1].total_games())
+        });
+
+        let mut body = template.render(&rows);
+        if !shared.is_empty() {
+            body.push(ColoredString::from_unformatted_str("Shared champions:"));
+            let shared_rows = shared
+                .iter()
+                .map(|(champion, stats)| {
+                    Self::row(
+                        champion.name().unwrap_or("Unknown"),
+                        stats.map(|stats| {
+                            format!(
+                                "{:.0}% WR - {} games",
+                                stats.winrate() * 100.0,
+                                stats.total_games()
+                            )
+                        }),
+                        stats.map(|stats| Some(f64::from(stats.winrate()))),
+                    )
+                })
+                .collect::<Vec<_>>();
+            body.extend(template.render(&shared_rows));
+        }
+
+        body
+    }
+}
+
+/// Returns the index of the player with the highest score, `None` if tied or missing.
+fn best(scores: [Option<f64>; 2]) -> Option<usize> {
+    match scores {
+        [Some(first), Some(second)] if first != second => Some(usize::from(second > first)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case([Some(3.0), Some(2.0)] => Some(0) ; "first is better")]
+    #[test_case([Some(-150.0), Some(20.0)] => Some(1) ; "second is better")]
+    #[test_case([Some(1.0), Some(1.0)] => None ; "tie")]
+    #[test_case([None, Some(1.0)] => None ; "missing score")]
+    fn test_best(scores: [Option<f64>; 2]) -> Option<usize> {
+        best(scores)
+    }
+}
@@ -0,0 +1,171 @@
+//! Template fields shared by several sections.
+
+use crate::{
+    api::tooling::static_data::StaticNames,
+    display::{
+        template::{Field, Fields},
+        theme,
+        utils::widgets,
+    },
+    models::{
+        champion_stats::GameStats,
+        lane_diff::{AverageLaneDiff, Minute},
+        loadout::RunePage,
+    },
+};
+
+/// Width of the `winrate_bar` field.
+const WINRATE_BAR_WIDTH: usize = 10;
+
+/// Returns a ratio as a percentage without the `%` sign, e.g. `55`.
+pub fn percentage(ratio: f64) -> Field {
+    Field::text(format!("{:.0}", ratio * 100.0))
+}
+
+/// Returns the KDA ratio, e.g. `3.2`, or `PERFECT` without deaths.
+pub fn kda_ratio(kda: Option<f64>) -> String {
+    kda.map_or_else(|| "PERFECT".to_string(), |kda| format!("{kda:.1}"))
+}
+
+/// Returns the KDA ratio followed by `KDA`, e.g. `3.2 KDA`, or `PERFECT` without deaths.
+pub fn kda_text(kda: Option<f64>) -> String {
+    match kda {
+        Some(_) => format!("{} KDA", kda_ratio(kda)),
+        None => kda_ratio(kda),
+    }
+}
+
+/// Inserts the number of `games`, `wins` and `losses`,
+/// along with the `winrate` and `winrate_bar` when there are games.
+pub fn insert_results(fields: &mut Fields, wins: u32, losses: u32) {
+    let games = wins + losses;
+    fields.insert("games", Field::text(games.to_string()));
+    fields.insert("wins", Field::text(wins.to_string()));
+    fields.insert("losses", Field::text(losses.to_string()));
+
+    if games > 0 {
+        let winrate = f64::from(wins) / f64::from(games);
+        fields.insert("winrate", percentage(winrate));
+        fields.insert(
+            "winrate_bar",
+            Field::widget(widgets::progress_bar(
+                winrate,
+                WINRATE_BAR_WIDTH,
+                theme::current().win,
+            )),
+        );
+    }
+}
+
+/// Inserts the `kda_ratio` and the CS per minute (`cs_per_min`) of the games.
+pub fn insert_kda_cs(fields: &mut Fields, stats: &GameStats) {
+    fields.insert("kda_ratio", Field::text(kda_text(stats.kda())));
+    fields.insert("cs_per_min", Field::text(format!("{:.1}", stats.cspm())));
+}
+
+/// Inserts the kill participation, damage share, damage / gold / vision score per minute,
+/// control wards and objective participation. Shares are percentages without the `%` sign.
+/// Control wards are displayed with a decimal when `averaged` over several games.
+pub fn insert_performance(fields: &mut Fields, stats: &GameStats, averaged: bool) {
+    if let Some(kill_participation) = stats.kill_participation() {
+        fields.insert("kp", percentage(kill_participation));
+    }
+    if let Some(damage_share) = stats.damage_share() {
+        fields.insert("damage_share", percentage(damage_share));
+    }
+    if let Some(objective_participation) = stats.objective_participation() {
+        fields.insert(
+            "objective_participation",
+            percentage(objective_participation),
+        );
+    }
+    fields.insert("dpm", Field::text(format!("{:.0}", stats.dpm())));
+    fields.insert("gpm", Field::text(format!("{:.0}", stats.gpm())));
+    fields.insert("vspm", Field::text(format!("{:.2}", stats.vspm())));
+    fields.insert(
+        "control_wards",
+        Field::text(format!(
+            "{:.decimals$}",
+            stats.control_wards(),
+            decimals = usize::from(averaged)
+        )),
+    );
+}
+
+/// Inserts the gold (`gd`), CS (`csd`), XP (`xpd`) and level (`lvld`) differences
+/// with the lane opponent, indexed by minute.
+/// Levels are displayed with a decimal when `averaged` over several games.
+pub fn insert_lane_diffs(
+    fields: &mut Fields,
+    diffs: impl IntoIterator<Item = (Minute, AverageLaneDiff)>,
+    averaged: bool,
+) {
+    let diffs = diffs.into_iter().collect::<Vec<_>>();
+    let indexed = |value: fn(&AverageLaneDiff) -> f64, decimals: usize| {
+        Field::indexed(diffs.iter().map(|(minute, diff)| {
+            let value = round(value(diff), decimals);
+            (*minute, Field::number(value, format!("{value:.decimals$}")))
+        }))
+    };
+
+    fields.insert("gd", indexed(|diff| diff.gold, 0));
+    fields.insert("csd", indexed(|diff| diff.cs, 0));
+    fields.insert("xpd", indexed(|diff| diff.xp, 0));
+    fields.insert("lvld", indexed(|diff| diff.level, usize::from(averaged)));
+}
+
+/// Returns the names of the items, separated by commas.
+pub fn items_text(names: &StaticNames, items: &[i32]) -> String {
+    items
+        .iter()
+        .map(|&item| names.item_name(item))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns the keystone and the secondary tree, e.g. `Electrocute/Sorcery`.
+pub fn runes_text(names: &StaticNames, runes: RunePage) -> String {
+    format!(
+        "{}/{}",
+        names.rune_name(runes.keystone),
+        names.rune_name(runes.secondary_tree)
+    )
+}
+
+/// Rounds to the given number of decimals, without negative zeros.
+fn round(value: f64, decimals: usize) -> f64 {
+    let factor = 10_f64.powi(decimals as i32);
+    let rounded = (value * factor).round() / factor;
+    if rounded == 0.0 {
+        0.0
+    } else {
+        rounded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::template::Template;
+    use test_case::test_case;
+
+    #[test_case(Some(3.25) => "3.2 KDA" ; "ratio")]
+    #[test_case(None => "PERFECT" ; "no deaths")]
+    fn test_kda_text(kda: Option<f64>) -> String {
+        kda_text(kda)
+    }
+
+    #[test_case(11, 9 => "20 games 11W 9L 55%" ; "games")]
+    #[test_case(0, 0 => "0 games 0W 0L" ; "no winrate without games")]
+    fn test_insert_results(wins: u32, losses: u32) -> String {
+        let mut fields = Fields::new();
+        insert_results(&mut fields, wins, losses);
+
+        Template::parse("{games} games {wins}W {losses}L[ {winrate}%]")
+            .expect("Template should be valid")
+            .render(&[fields])[0]
+            .iter()
+            .map(|c| c.character())
+            .collect()
+    }
+}
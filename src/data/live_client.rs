@@ -0,0 +1,124 @@
+//! Statistics of the player in the game in progress, from the game client.
+
+use crate::{
+    api::live_client::AllGameData,
+    data::fields,
+    display::{theme, DisplayableSection},
+    models::matches::Kda,
+};
+use lolfetch_color::ColoredString;
+
+/// Slot of the trinket, left out of the items.
+const TRINKET_SLOT: i32 = 6;
+
+pub struct LiveStats {
+    data: AllGameData,
+}
+
+impl LiveStats {
+    pub const fn new(data: AllGameData) -> Self {
+        Self { data }
+    }
+
+    /// Returns the kills of the team of the player and of the enemy team.
+    fn team_kills(&self, team: &str) -> (i32, i32) {
+        self.data
+            .all_players
+            .iter()
+            .fold((0, 0), |(allies, enemies), player| {
+                if player.team == team {
+                    (allies + player.scores.kills, enemies)
+                } else {
+                    (allies, enemies + player.scores.kills)
+                }
+            })
+    }
+}
+
+impl DisplayableSection for LiveStats {
+    fn header(&self) -> Option<String> {
+        let time = self.data.game_data.game_time as i64;
+        Some(format!(
+            "Live Game - {} - {:02}:{:02}",
+            self.data.game_data.game_mode,
+            time / 60,
+            time % 60
+        ))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+
+        let Some(me) = self.data.me() else {
+            return vec![ColoredString::from_unformatted_str(
+                "The active player is spectating",
+            )];
+        };
+
+        let mut body = vec![ColoredString::from_unformatted_str(&format!(
+            "Champion: {} - Level {}",
+            me.champion_name, me.level
+        ))];
+
+        if me.is_dead {
+            body.push(ColoredString::from_str(
+                &format!("Dead - respawn in {:.0}s", me.respawn_timer),
+                Some(theme.negative),
+                None,
+            ));
+        }
+
+        let kda = Kda(me.scores.kills, me.scores.deaths, me.scores.assists);
+        body.push(ColoredString::from_unformatted_str(&format!(
+            "KDA: {kda} ({})",
+            fields::kda_ratio(kda.get_kda())
+        )));
+
+        let minutes = self.data.game_data.game_time / 60.0;
+        let mut cs = format!("CS: {}", me.scores.creep_score);
+        if minutes >= 1.0 {
+            cs.push_str(&format!(
+                " ({:.1} CS/M)",
+                f64::from(me.scores.creep_score) / minutes
+            ));
+        }
+        body.push(ColoredString::from_unformatted_str(&cs));
+
+        body.push(ColoredString::from_unformatted_str(&format!(
+            "Gold: {:.0} - Vision score: {:.0}",
+            self.data.active_player.current_gold, me.scores.ward_score
+        )));
+
+        let mut items = me
+            .items
+            .iter()
+            .filter(|item| item.slot != TRINKET_SLOT)
+            .collect::<Vec<_>>();
+        items.sort_by_key(|item| item.slot);
+        if !items.is_empty() {
+            body.push(ColoredString::from_unformatted_str(&format!(
+                "Items: {}",
+                items
+                    .iter()
+                    .map(|item| item.display_name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        }
+
+        let (allies, enemies) = self.team_kills(&me.team);
+        let mut kills = ColoredString::from_unformatted_str("Team kills: ");
+        kills.push_str(
+            &format!("{allies} - {enemies}"),
+            match allies.cmp(&enemies) {
+                std::cmp::Ordering::Greater => Some(theme.positive),
+                std::cmp::Ordering::Less => Some(theme.negative),
+                std::cmp::Ordering::Equal => None,
+            },
+            None,
+        );
+        body.push(kills);
+
+        body
+    }
+}
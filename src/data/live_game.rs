@@ -0,0 +1,237 @@
+//! Participants of the game the summoner is currently playing.
+
+use super::fields;
+use crate::{
+    api::{
+        live::{LiveGame as LiveGameData, LivePlayer},
+        tooling::static_data::StaticNames,
+    },
+    display::{
+        template::{Field, Fields, Template},
+        theme,
+        utils::colors::RankColorGetter,
+        DisplayableSection,
+    },
+    models::{loadout::RunePage, matches::queue_name, ranked::RankedInfo},
+};
+use lolfetch_color::ColoredString;
+use riven::{
+    consts::{Champion, Team},
+    models::summoner_v4::Summoner,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub struct LiveGame {
+    game: LiveGameData,
+    /// PUUID of the summoner, whose row is highlighted.
+    puuid: String,
+    /// Time since the start of the game, in seconds, `None` while loading.
+    elapsed: Option<i64>,
+    names: &'static StaticNames,
+    template: Template,
+}
+
+impl LiveGame {
+    pub const DEFAULT_TEMPLATE: &'static str = "{riot_id:<}  {champion:<}  {rank:<}  {spells:<}  {runes:<}[  {champion_games:>} games {champion_winrate:>3}% WR]";
+
+    /// Fields available in the row template.
+    pub const FIELDS: &'static [&'static str] = &[
+        "riot_id",
+        "champion",
+        "rank",
+        "short_rank",
+        "winrate",
+        "games",
+        "runes",
+        "spells",
+        "champion_games",
+        "champion_winrate",
+        "champion_kda",
+    ];
+
+    pub fn new(
+        game: LiveGameData,
+        summoner: &Summoner,
+        names: &'static StaticNames,
+        template: &Template,
+    ) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis() as i64);
+        let start = game.info.game_start_time;
+
+        Self {
+            game,
+            puuid: summoner.puuid.clone(),
+            elapsed: (start > 0).then(|| (now - start).max(0) / 1000),
+            names,
+            template: template.clone(),
+        }
+    }
+
+    fn row(&self, player: &LivePlayer) -> Fields {
+        let theme = theme::current();
+        let participant = &player.participant;
+        let ranked = player
+            .ranked
+            .clone()
+            .and_then(RankedInfo::from_entry)
+            .filter(|ranked| ranked.tier.is_ranked());
+        let rank_color = ranked
+            .as_ref()
+            .and_then(|ranked| ranked.tier.get_rank_color());
+
+        let riot_id = participant
+            .riot_id
+            .clone()
+            .unwrap_or_else(|| "Unknown".to_string());
+        let is_summoner = participant.puuid.as_deref() == Some(self.puuid.as_str());
+        let runes = participant.perks.as_ref().and_then(|perks| {
+            Some(RunePage {
+                keystone: i32::try_from(*perks.perk_ids.first()?).ok()?,
+                secondary_tree: i32::try_from(perks.perk_sub_style).ok()?,
+            })
+        });
+        let spell = |id: i64| {
+            i32::try_from(id).map_or_else(
+                |_| format!("Spell {id}"),
+                |id| self.names.summoner_spell_name(id),
+            )
+        };
+
+        let mut fields = Fields::from([
+            (
+                "riot_id",
+                Field::colored(riot_id, is_summoner.then_some(theme.accent)),
+            ),
+            (
+                "champion",
+                Field::text(champion_name(participant.champion_id)),
+            ),
+            (
+                "rank",
+                Field::colored(
+                    ranked
+                        .as_ref()
+                        .map_or_else(|| "Unranked".to_string(), ToString::to_string),
+                    rank_color,
+                ),
+            ),
+            (
+                "short_rank",
+                Field::colored(
+                    ranked
+                        .as_ref()
+                        .map_or_else(|| "-".to_string(), RankedInfo::short_rank),
+                    rank_color,
+                ),
+            ),
+            (
+                "spells",
+                Field::text(format!(
+                    "{}/{}",
+                    spell(participant.spell1_id),
+                    spell(participant.spell2_id)
+                )),
+            ),
+        ]);
+
+        if let Some(ranked) = &ranked {
+            fields.insert(
+                "games",
+                Field::text((ranked.wins + ranked.losses).to_string()),
+            );
+            if let Some(winrate) = ranked.get_winrate() {
+                fields.insert("winrate", fields::percentage(f64::from(winrate)));
+            }
+        }
+        if let Some(runes) = runes {
+            fields.insert("runes", Field::text(fields::runes_text(self.names, runes)));
+        }
+        if let Some(stats) = player
+            .champion_stats
+            .as_ref()
+            .filter(|stats| stats.total_games() > 0)
+        {
+            fields.insert(
+                "champion_games",
+                Field::text(stats.total_games().to_string()),
+            );
+            fields.insert(
+                "champion_winrate",
+                fields::percentage(f64::from(stats.winrate())),
+            );
+            fields.insert("champion_kda", Field::text(fields::kda_text(stats.kda())));
+        }
+
+        fields
+    }
+
+    /// Returns the champions banned by a team, if any.
+    fn bans(&self, team: Team) -> Option<String> {
+        let bans = self
+            .game
+            .info
+            .banned_champions
+            .iter()
+            .filter(|ban| ban.team_id == team && ban.champion_id != Champion::NONE)
+            .map(|ban| champion_name(ban.champion_id))
+            .collect::<Vec<_>>();
+
+        (!bans.is_empty()).then(|| bans.join(", "))
+    }
+}
+
+impl DisplayableSection for LiveGame {
+    fn header(&self) -> Option<String> {
+        let time = self.elapsed.map_or_else(
+            || "Loading".to_string(),
+            |elapsed| format!("{:02}:{:02}", elapsed / 60, elapsed % 60),
+        );
+
+        Some(format!(
+            "Live Game - {} - {time}",
+            queue_name(self.game.info.game_queue_config_id)
+        ))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+
+        // Both teams are rendered together so that their columns are aligned
+        let (blue, red): (Vec<_>, Vec<_>) = self
+            .game
+            .players
+            .iter()
+            .partition(|player| player.participant.team_id == Team::BLUE);
+        let rows = blue
+            .iter()
+            .chain(&red)
+            .map(|player| self.row(player))
+            .collect::<Vec<_>>();
+        let mut rendered = self.template.render(&rows);
+        let red_rows = rendered.split_off(blue.len());
+
+        let mut body = Vec::new();
+        for (name, team, rows) in [
+            ("Blue side", Team::BLUE, rendered),
+            ("Red side", Team::RED, red_rows),
+        ] {
+            if rows.is_empty() {
+                continue;
+            }
+            let mut label = ColoredString::from_str(name, Some(theme.accent), None);
+            if let Some(bans) = self.bans(team) {
+                label.push_unformatted_str(&format!(" - Bans: {bans}"));
+            }
+            body.push(label);
+            body.extend(rows);
+        }
+
+        body
+    }
+}
+
+fn champion_name(champion: Champion) -> String {
+    champion.name().unwrap_or("Unknown").to_string()
+}
@@ -0,0 +1,106 @@
+//! LP over time, from the rank snapshots recorded on each run.
+
+use crate::{
+    display::{theme, utils::widgets, DisplayableSection},
+    models::ranked::RankSnapshot,
+};
+use lolfetch_color::ColoredString;
+
+/// Number of snapshots displayed in the chart, one per column.
+const MAX_SNAPSHOTS: usize = 40;
+
+/// Number of rows of the chart.
+const CHART_HEIGHT: usize = 5;
+
+pub struct LpHistory {
+    /// Most recent snapshots, sorted chronologically.
+    snapshots: Vec<RankSnapshot>,
+}
+
+impl LpHistory {
+    pub fn new(snapshots: &[RankSnapshot]) -> Self {
+        Self {
+            snapshots: snapshots[snapshots.len().saturating_sub(MAX_SNAPSHOTS)..].to_vec(),
+        }
+    }
+}
+
+impl DisplayableSection for LpHistory {
+    fn header(&self) -> Option<String> {
+        // Each change is between two consecutive snapshots
+        Some(format!(
+            "LP History (last {} changes)",
+            self.snapshots.len().saturating_sub(1)
+        ))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+        let theme = theme::current();
+
+        let (Some(first), Some(last)) = (self.snapshots.first(), self.snapshots.last()) else {
+            return vec![ColoredString::from_unformatted_str(
+                "No rank recorded yet, run lolfetch again after playing",
+            )];
+        };
+
+        let highest = self
+            .snapshots
+            .iter()
+            .max_by_key(|snapshot| snapshot.absolute_lp())
+            .expect("There is at least one snapshot");
+        let lowest = self
+            .snapshots
+            .iter()
+            .min_by_key(|snapshot| snapshot.absolute_lp())
+            .expect("There is at least one snapshot");
+
+        // Highest rank on the first row, lowest rank on the last one
+        let labels = [highest.short_rank(), lowest.short_rank()];
+        let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+
+        let values = self
+            .snapshots
+            .iter()
+            .map(|snapshot| f64::from(snapshot.absolute_lp()))
+            .collect::<Vec<_>>();
+        let chart = widgets::area_chart(&values, CHART_HEIGHT, theme.accent);
+
+        let mut body = chart
+            .into_iter()
+            .enumerate()
+            .map(|(row, chart_row)| {
+                let label = match row {
+                    0 => labels[0].as_str(),
+                    row if row == CHART_HEIGHT - 1 => labels[1].as_str(),
+                    _ => "",
+                };
+                let mut line =
+                    ColoredString::from_unformatted_str(&format!("{label:>label_width$} │"));
+                line.join(&chart_row);
+                line
+            })
+            .collect::<Vec<_>>();
+
+        let net = last.absolute_lp() - first.absolute_lp();
+        let days = (last.timestamp - first.timestamp) / DAY_MS;
+        let mut summary = ColoredString::from_unformatted_str("Net: ");
+        summary.push_str(
+            &format!("{net:+} LP"),
+            Some(if net >= 0 {
+                theme.positive
+            } else {
+                theme.negative
+            }),
+            None,
+        );
+        summary.push_unformatted_str(&format!(
+            " over {days} day{}",
+            if days == 1 { "" } else { "s" }
+        ));
+        body.push(summary);
+
+        body
+    }
+}
@@ -1,29 +1,165 @@
 use lolfetch_color::ColoredString;
-use riven::models::champion_mastery_v4::ChampionMastery;
+use riven::{
+    consts::Champion,
+    models::{champion_mastery_v4::ChampionMastery, summoner_v4::Summoner},
+};
+use std::collections::HashMap;
 
-use crate::display::DisplayableSection;
+use crate::{
+    data::fields,
+    display::{
+        template::{Field, Fields, Template},
+        theme,
+        utils::{time_ago, widgets},
+        DisplayableSection,
+    },
+    models::{
+        champion_stats::GameStats,
+        matches::{MatchInfo, MatchPlayerInfo},
+    },
+};
 
 pub struct Mastery {
     masteries: Vec<ChampionMastery>,
+    /// Sum of the levels of every champion.
+    score: i32,
+    /// Sum of the points of every champion.
+    total_points: i64,
+    champions: usize,
+    /// Statistics of the cached games on the displayed champions.
+    stats: HashMap<Champion, GameStats>,
+    template: Template,
 }
 
 impl Mastery {
-    pub fn new(masteries: Vec<ChampionMastery>, max_champs: i32) -> Self {
+    pub const DEFAULT_TEMPLATE: &'static str =
+        "{rank}. {champion:<} - Level {level} - {points} points - {last_played}[ - {winrate}% WR in {games} games]";
+
+    /// Fields available in the row template.
+    pub const FIELDS: &'static [&'static str] = &[
+        "rank",
+        "champion",
+        "level",
+        "points",
+        "points_bar",
+        "points_to_next",
+        "marks",
+        "milestone",
+        "grades",
+        "last_played",
+        "games",
+        "winrate",
+    ];
+
+    pub fn new(
+        masteries: Vec<ChampionMastery>,
+        matches: &[MatchInfo],
+        summoner: &Summoner,
+        max_champs: i32,
+        template: &Template,
+    ) -> Self {
+        let score = masteries.iter().map(|mastery| mastery.champion_level).sum();
+        let total_points = masteries
+            .iter()
+            .map(|mastery| i64::from(mastery.champion_points))
+            .sum();
+        let champions = masteries.len();
+        let masteries = masteries
+            .into_iter()
+            .take(max_champs as usize)
+            .collect::<Vec<_>>();
+
+        let mut stats = HashMap::new();
+        for game in matches
+            .iter()
+            .filter_map(|game| MatchPlayerInfo::from_match_info(game, summoner).ok())
+            .filter(|game| {
+                masteries
+                    .iter()
+                    .any(|mastery| mastery.champion_id == game.champion)
+            })
+        {
+            stats
+                .entry(game.champion)
+                .or_insert_with(GameStats::new)
+                .add_game(&game);
+        }
+
         Self {
-            masteries: masteries.into_iter().take(max_champs as usize).collect(),
+            masteries,
+            score,
+            total_points,
+            champions,
+            stats,
+            template: template.clone(),
         }
     }
 
-    pub fn max_champion_name_width(&self) -> Option<usize> {
+    /// Returns the displayed champions.
+    pub fn champions(&self) -> Vec<Champion> {
         self.masteries
             .iter()
-            .map(|m| {
-                m.champion_id
-                    .name()
-                    .expect("Failed to get champion name")
-                    .len()
-            })
-            .max()
+            .map(|mastery| mastery.champion_id)
+            .collect()
+    }
+
+    fn row(&self, rank: usize, mastery: &ChampionMastery, max_points: i32) -> Fields {
+        const POINTS_BAR_WIDTH: usize = 10;
+
+        let mut fields = Fields::from([
+            ("rank", Field::text(rank.to_string())),
+            (
+                "champion",
+                Field::text(
+                    mastery
+                        .champion_id
+                        .name()
+                        .expect("Failed to get champion name"),
+                ),
+            ),
+            ("level", Field::text(mastery.champion_level.to_string())),
+            ("points", Field::text(mastery.champion_points.to_string())),
+            (
+                "points_bar",
+                Field::widget(widgets::horizontal_bar(
+                    f64::from(mastery.champion_points),
+                    f64::from(max_points),
+                    POINTS_BAR_WIDTH,
+                    theme::current().accent,
+                )),
+            ),
+            (
+                "points_to_next",
+                // Negative once the points are reached, the marks being still required
+                Field::text(mastery.champion_points_until_next_level.max(0).to_string()),
+            ),
+            (
+                "marks",
+                Field::text(format!(
+                    "{}/{}",
+                    mastery.tokens_earned, mastery.mark_required_for_next_level
+                )),
+            ),
+            (
+                "milestone",
+                Field::text(mastery.champion_season_milestone.to_string()),
+            ),
+            ("last_played", Field::text(time_ago(mastery.last_play_time))),
+        ]);
+
+        if let Some(grades) = mastery
+            .milestone_grades
+            .as_ref()
+            .filter(|grades| !grades.is_empty())
+        {
+            fields.insert("grades", Field::text(grades.join(" ")));
+        }
+        if let Some(stats) = self.stats.get(&mastery.champion_id) {
+            fields.insert("games", Field::text(stats.total_games().to_string()));
+            fields.insert("winrate", fields::percentage(f64::from(stats.winrate())));
+        }
+
+        fields
     }
 }
 
@@ -33,22 +169,93 @@ impl DisplayableSection for Mastery {
     }
 
     fn body(&self) -> Vec<ColoredString> {
-        let mut body = Vec::new();
-        let max_width = self.max_champion_name_width().expect("No masteries found");
-        for (i, mastery) in self.masteries.iter().enumerate() {
-            let mastery_str = format!(
-                "{}. {:<width$} - Level {} - {} points",
-                i + 1,
-                mastery
-                    .champion_id
-                    .name()
-                    .expect("Failed to get champion name"),
-                mastery.champion_level,
-                mastery.champion_points,
-                width = max_width
-            );
-            body.push(ColoredString::from_unformatted_str(&mastery_str));
-        }
+        let max_points = self
+            .masteries
+            .iter()
+            .map(|mastery| mastery.champion_points)
+            .max()
+            .unwrap_or(0);
+
+        let rows = self
+            .masteries
+            .iter()
+            .enumerate()
+            .map(|(i, mastery)| self.row(i + 1, mastery, max_points))
+            .collect::<Vec<_>>();
+
+        let mut body = vec![ColoredString::from_unformatted_str(&format!(
+            "Mastery score {} - {} points on {} champions",
+            self.score, self.total_points, self.champions
+        ))];
+        body.extend(self.template.render(&rows));
         body
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn mastery(points_until_next_level: i64, grades: &[&str]) -> ChampionMastery {
+        serde_json::from_value(json!({
+            "puuid": "puuid",
+            "championId": 103,
+            "championLevel": 12,
+            "championPoints": 150_000,
+            "championPointsSinceLastLevel": 10_000,
+            "championPointsUntilNextLevel": points_until_next_level,
+            "lastPlayTime": 0,
+            "markRequiredForNextLevel": 2,
+            "tokensEarned": 1,
+            "championSeasonMilestone": 3,
+            "milestoneGrades": grades,
+            "nextSeasonMilestone": {
+                "requireGradeCounts": { "A-": 1 },
+                "rewardMarks": 1,
+                "bonus": false,
+                "totalGamesRequires": 1,
+            },
+        }))
+        .expect("Mastery should be valid")
+    }
+
+    fn render(mastery: &Mastery) -> Vec<String> {
+        mastery
+            .body()
+            .iter()
+            .map(|line| line.iter().map(|c| c.character()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_progress() {
+        let template =
+            Template::parse("{champion} {points_to_next} {marks} M{milestone}[ {grades}]")
+                .expect("Template should be valid");
+        let summoner = Summoner {
+            id: String::new(),
+            account_id: String::new(),
+            puuid: "puuid".to_string(),
+            profile_icon_id: 0,
+            revision_date: 0,
+            summoner_level: 1,
+        };
+
+        let mastery = Mastery::new(
+            vec![mastery(-500, &["S", "A-"]), mastery(1_000, &[])],
+            &[],
+            &summoner,
+            10,
+            &template,
+        );
+        assert_eq!(
+            render(&mastery),
+            [
+                "Mastery score 24 - 300000 points on 2 champions",
+                "Ahri 0 1/2 M3 S A-",
+                "Ahri 1000 1/2 M3",
+            ]
+        );
+    }
+}
@@ -1,43 +1,83 @@
-use crate::display::DisplayableSection;
+use crate::api::tooling::static_data::StaticNames;
+use crate::data::fields;
+use crate::display::{
+    template::{Field, Fields, Template},
+    theme, DisplayableSection,
+};
 use crate::models::champion_stats::GameStats;
-use crate::models::matches::{GameResult, MatchInfo, MatchPlayerInfo};
+use crate::models::matches::{GameResult, Kda, MatchId, MatchInfo, MatchPlayerInfo};
 use lolfetch_color::ColoredString;
 use riven::models::summoner_v4::Summoner;
-use termcolor::Color;
+use std::collections::HashMap;
 
 pub struct MatchHistory {
-    matches: Vec<MatchPlayerInfo>,
+    /// Matches with the LP won or lost, when known.
+    matches: Vec<(MatchPlayerInfo, Option<i32>)>,
+    names: &'static StaticNames,
+    template: Template,
 }
 
 impl MatchHistory {
-    pub fn new(matches: &[MatchInfo], summoner: &Summoner, max_games: i32) -> Self {
+    pub const DEFAULT_TEMPLATE: &'static str = "{duration} - {result} - {position} - {champion:<} - {kda:<8} - {kda_ratio} - {cs_per_min} CS/M[ - GD@15: {gd@15:+}][ - {lp:+} LP][ - {runes}]";
+
+    /// Fields available in the row template.
+    pub const FIELDS: &'static [&'static str] = &[
+        "duration",
+        "result",
+        "position",
+        "champion",
+        "kda",
+        "kills",
+        "deaths",
+        "assists",
+        "kda_ratio",
+        "cs",
+        "cs_per_min",
+        "kp",
+        "damage_share",
+        "dpm",
+        "gpm",
+        "vspm",
+        "control_wards",
+        "objective_participation",
+        "gd@<minute>",
+        "csd@<minute>",
+        "xpd@<minute>",
+        "lvld@<minute>",
+        "lp",
+        "items",
+        "keystone",
+        "secondary_tree",
+        "runes",
+        "spells",
+    ];
+
+    pub fn new(
+        matches: &[MatchInfo],
+        summoner: &Summoner,
+        max_games: i32,
+        lp_changes: &HashMap<MatchId, i32>,
+        names: &'static StaticNames,
+        template: &Template,
+    ) -> Self {
         let match_infos = matches
             .iter()
             .take(max_games as usize)
             .map(|game| {
-                MatchPlayerInfo::from_match_info(game, summoner).expect(
+                let match_info = MatchPlayerInfo::from_match_info(game, summoner).expect(
                     "
             Failed to get match player info",
-                )
+                );
+                (match_info, lp_changes.get(&game.id).copied())
             })
             .collect::<Vec<_>>();
 
         Self {
             matches: match_infos,
+            names,
+            template: template.clone(),
         }
     }
-
-    pub fn max_champion_name_width(&self) -> Option<usize> {
-        self.matches
-            .iter()
-            .map(|m| {
-                m.champion
-                    .name()
-                    .expect("Failed to get champion name")
-                    .len()
-            })
-            .max()
-    }
 }
 
 impl DisplayableSection for MatchHistory {
@@ -46,67 +86,96 @@ impl DisplayableSection for MatchHistory {
     }
 
     fn body(&self) -> Vec<ColoredString> {
-        let mut body = Vec::new();
-
-        let max_width = self.max_champion_name_width().expect("No matches found");
+        let theme = theme::current();
 
-        for match_info in &self.matches {
-            let mut match_body = ColoredString::new();
-
-            match_body.push_unformatted_str(&format!(
-                "{:02}:{:02} - ",
-                match_info.time_played / 60,
-                match_info.time_played % 60
-            ));
-
-            match match_info.game_result {
-                GameResult::Win => {
-                    match_body.push_str("W", Some(Color::Blue), None);
+        let rows = self
+            .matches
+            .iter()
+            .map(|(match_info, lp_change)| {
+                let game_stats = GameStats::from(match_info);
+                let Kda(kills, deaths, assists) = match_info.kda;
+
+                let mut fields = Fields::from([
+                    (
+                        "duration",
+                        Field::text(format!(
+                            "{:02}:{:02}",
+                            match_info.time_played / 60,
+                            match_info.time_played % 60
+                        )),
+                    ),
+                    (
+                        "result",
+                        match match_info.game_result {
+                            GameResult::Win => Field::colored("W", Some(theme.win)),
+                            GameResult::Loss => Field::colored("L", Some(theme.loss)),
+                        },
+                    ),
+                    (
+                        "position",
+                        Field::text(match_info.team_position.to_string()),
+                    ),
+                    (
+                        "champion",
+                        Field::text(
+                            match_info
+                                .champion
+                                .name()
+                                .expect("Failed to get champion name"),
+                        ),
+                    ),
+                    ("kda", Field::text(match_info.kda.to_string())),
+                    ("kills", Field::text(kills.to_string())),
+                    ("deaths", Field::text(deaths.to_string())),
+                    ("assists", Field::text(assists.to_string())),
+                    ("cs", Field::text(match_info.minions_killed.to_string())),
+                ]);
+
+                fields::insert_kda_cs(&mut fields, &game_stats);
+                fields::insert_performance(&mut fields, &game_stats, false);
+                fields::insert_lane_diffs(
+                    &mut fields,
+                    match_info
+                        .lane_diffs
+                        .iter()
+                        .map(|(&minute, &diff)| (minute, diff.into())),
+                    false,
+                );
+
+                let loadout = &match_info.loadout;
+                fields.insert(
+                    "items",
+                    Field::text(fields::items_text(self.names, &loadout.items)),
+                );
+                if let Some(runes) = loadout.runes {
+                    fields.insert(
+                        "keystone",
+                        Field::text(self.names.rune_name(runes.keystone)),
+                    );
+                    fields.insert(
+                        "secondary_tree",
+                        Field::text(self.names.rune_name(runes.secondary_tree)),
+                    );
+                    fields.insert("runes", Field::text(fields::runes_text(self.names, runes)));
                 }
-                GameResult::Loss => {
-                    match_body.push_str("L", Some(Color::Red), None);
+                fields.insert(
+                    "spells",
+                    Field::text(
+                        loadout
+                            .summoner_spells
+                            .map(|spell| self.names.summoner_spell_name(spell))
+                            .join("/"),
+                    ),
+                );
+
+                if let Some(lp) = lp_change {
+                    fields.insert("lp", Field::number(f64::from(*lp), lp.to_string()));
                 }
-            }
-
-            match_body.push_unformatted_str(&format!(
-                " - {} - {:<width$}",
-                match_info.team_position,
-                match_info
-                    .champion
-                    .name()
-                    .expect("Failed to get champion name"),
-                width = max_width
-            ));
-
-            match_body.push_unformatted_str(&format!(" - {:8} - ", match_info.kda.to_string()));
 
-            let game_stats = GameStats::from(match_info);
-
-            if let Some(kda) = game_stats.kda() {
-                match_body.push_unformatted_str(&format!("{kda:.1} KDA"));
-            } else {
-                match_body.push_unformatted_str("PERFECT");
-            }
-
-            match_body.push_unformatted_str(&format!(" - {:.1} CS/M", game_stats.cspm()));
-
-            if let Some(gold_diff) = match_info.gold_diff_15 {
-                let color = if gold_diff > 0 {
-                    Some(Color::Green)
-                } else {
-                    Some(Color::Red)
-                };
-
-                match_body.push_unformatted_str(" - GD@15: ");
-                if gold_diff > 0 {
-                    match_body.push_str("+", color, None);
-                }
-                match_body.push_str(&gold_diff.to_string(), color, None);
-            }
-
-            body.push(match_body);
-        }
+                fields
+            })
+            .collect::<Vec<_>>();
 
-        body
+        self.template.render(&rows)
     }
 }
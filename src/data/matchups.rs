@@ -0,0 +1,270 @@
+//! Statistics of a champion against each lane opponent.
+
+use crate::{
+    data::fields,
+    display::{
+        template::{Field, Fields, Template},
+        theme, DisplayableSection,
+    },
+    models::{
+        champion_stats::GameStats,
+        matches::{MatchInfo, MatchPlayerInfo},
+    },
+};
+use lolfetch_color::ColoredString;
+use riven::{consts::Champion, models::summoner_v4::Summoner};
+use std::collections::HashMap;
+
+/// Games needed against an opponent for the matchup to be ranked as one of the best or worst.
+const MIN_RANKED_GAMES: u32 = 2;
+
+/// Opponent and the statistics against it.
+type Matchup = (Champion, GameStats);
+
+pub struct Matchups {
+    champion: Champion,
+    /// Statistics against each opponent, from the most played.
+    matchups: Vec<Matchup>,
+    template: Template,
+}
+
+impl Matchups {
+    pub const DEFAULT_TEMPLATE: &'static str =
+        "{opponent:<} - {games:>} Played - {winrate:>3}% WR - {kda_ratio}[ - GD@15: {gd@15:+}]";
+
+    /// Fields available in the row template.
+    pub const FIELDS: &'static [&'static str] = &[
+        "opponent",
+        "games",
+        "wins",
+        "losses",
+        "winrate",
+        "winrate_bar",
+        "kda_ratio",
+        "cs_per_min",
+        "kp",
+        "damage_share",
+        "dpm",
+        "gpm",
+        "vspm",
+        "control_wards",
+        "objective_participation",
+        "gd@<minute>",
+        "csd@<minute>",
+        "xpd@<minute>",
+        "lvld@<minute>",
+    ];
+
+    /// Games without a lane opponent (e.g. outside of Summoner's Rift) are ignored.
+    pub fn new(
+        matches: &[MatchInfo],
+        summoner: &Summoner,
+        champion: Champion,
+        template: &Template,
+    ) -> Self {
+        Self::from_match_infos(
+            &player_infos(matches, summoner).collect::<Vec<_>>(),
+            champion,
+            template,
+        )
+    }
+
+    fn from_match_infos(
+        match_infos: &[MatchPlayerInfo],
+        champion: Champion,
+        template: &Template,
+    ) -> Self {
+        let mut stats = HashMap::new();
+        for match_info in match_infos {
+            if match_info.champion != champion {
+                continue;
+            }
+            if let Some(opponent) = match_info.lane_opponent {
+                stats
+                    .entry(opponent)
+                    .or_insert_with(GameStats::new)
+                    .add_game(match_info);
+            }
+        }
+
+        let mut matchups = stats.into_iter().collect::<Vec<_>>();
+        matchups.sort_by_key(|(opponent, stats)| {
+            (std::cmp::Reverse(stats.total_games()), opponent.name())
+        });
+
+        Self {
+            champion,
+            matchups,
+            template: template.clone(),
+        }
+    }
+
+    /// Returns the champions played by the summoner, from the most played.
+    pub fn played_champions(matches: &[MatchInfo], summoner: &Summoner) -> Vec<Champion> {
+        let mut games = HashMap::new();
+        for match_info in player_infos(matches, summoner) {
+            *games.entry(match_info.champion).or_insert(0) += 1;
+        }
+
+        let mut champions = games.into_iter().collect::<Vec<_>>();
+        champions.sort_by_key(|(champion, games)| (std::cmp::Reverse(*games), champion.name()));
+        champions
+            .into_iter()
+            .map(|(champion, _)| champion)
+            .collect()
+    }
+
+    /// Returns the best and worst matchups by winrate, among the ones played enough.
+    fn best_and_worst(&self) -> Option<(&Matchup, &Matchup)> {
+        let ranked = self
+            .matchups
+            .iter()
+            .filter(|(_, stats)| stats.total_games() >= MIN_RANKED_GAMES)
+            .collect::<Vec<_>>();
+        if ranked.len() < 2 {
+            return None;
+        }
+
+        let key = |(_, stats): &&Matchup| ((stats.winrate() * 1000.0) as i32, stats.total_games());
+        let best = ranked.iter().copied().max_by_key(key)?;
+        let worst = ranked.iter().copied().min_by_key(key)?;
+        Some((best, worst))
+    }
+}
+
+/// Returns the games of the summoner, skipping the ones that cannot be parsed (e.g. without position).
+fn player_infos<'a>(
+    matches: &'a [MatchInfo],
+    summoner: &'a Summoner,
+) -> impl Iterator<Item = MatchPlayerInfo> + 'a {
+    matches
+        .iter()
+        .filter_map(|game| MatchPlayerInfo::from_match_info(game, summoner).ok())
+}
+
+impl DisplayableSection for Matchups {
+    fn header(&self) -> Option<String> {
+        Some(format!(
+            "{} Matchups",
+            self.champion.name().unwrap_or("Unknown")
+        ))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+
+        if self.matchups.is_empty() {
+            return vec![ColoredString::from_unformatted_str(
+                "No cached games against a lane opponent",
+            )];
+        }
+
+        let mut body = Vec::new();
+
+        if let Some(((best, best_stats), (worst, worst_stats))) = self.best_and_worst() {
+            let mut line = ColoredString::from_unformatted_str("Best: ");
+            line.push_str(
+                &format!(
+                    "{} ({:.0}%)",
+                    best.name().unwrap_or("Unknown"),
+                    best_stats.winrate() * 100.0
+                ),
+                Some(theme.win),
+                None,
+            );
+            line.push_unformatted_str(" - Worst: ");
+            line.push_str(
+                &format!(
+                    "{} ({:.0}%)",
+                    worst.name().unwrap_or("Unknown"),
+                    worst_stats.winrate() * 100.0
+                ),
+                Some(theme.loss),
+                None,
+            );
+            body.push(line);
+        }
+
+        let rows = self
+            .matchups
+            .iter()
+            .map(|(opponent, stats)| {
+                let mut fields = Fields::from([(
+                    "opponent",
+                    Field::text(opponent.name().unwrap_or("Unknown")),
+                )]);
+                fields::insert_results(&mut fields, stats.wins(), stats.losses());
+                fields::insert_kda_cs(&mut fields, stats);
+                fields::insert_performance(&mut fields, stats, true);
+                fields::insert_lane_diffs(&mut fields, stats.lane_diffs().averages(), true);
+                fields
+            })
+            .collect::<Vec<_>>();
+
+        body.extend(self.template.render(&rows));
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::matches::{GameResult, TeamPosition};
+    use test_case::test_case;
+
+    fn game(champion: Champion, opponent: Option<Champion>, won: bool) -> MatchPlayerInfo {
+        let result = if won {
+            GameResult::Win
+        } else {
+            GameResult::Loss
+        };
+        MatchPlayerInfo {
+            lane_opponent: opponent,
+            ..MatchPlayerInfo::test(champion, TeamPosition::Mid, result)
+        }
+    }
+
+    fn matchups(games: &[MatchPlayerInfo]) -> Matchups {
+        let template = Template::parse(Matchups::DEFAULT_TEMPLATE).unwrap();
+        Matchups::from_match_infos(games, Champion::AHRI, &template)
+    }
+
+    #[test]
+    fn test_games_per_opponent() {
+        let games = [
+            game(Champion::AHRI, Some(Champion::ZED), true),
+            game(Champion::AHRI, Some(Champion::SYNDRA), false),
+            game(Champion::AHRI, Some(Champion::ZED), false),
+            game(Champion::AHRI, None, true),
+            game(Champion::LUX, Some(Champion::ZED), true),
+        ];
+        let played = matchups(&games)
+            .matchups
+            .iter()
+            .map(|(opponent, stats)| (*opponent, stats.total_games()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(played, [(Champion::ZED, 2), (Champion::SYNDRA, 1)]);
+    }
+
+    #[test_case(&[true, true], &[false, false] => Some((Champion::ZED, Champion::SYNDRA)) ; "best and worst")]
+    #[test_case(&[true, true], &[false] => None ; "not enough games")]
+    #[test_case(&[], &[] => None ; "no games")]
+    fn test_best_and_worst(
+        against_zed: &[bool],
+        against_syndra: &[bool],
+    ) -> Option<(Champion, Champion)> {
+        let games = against_zed
+            .iter()
+            .map(|&won| game(Champion::AHRI, Some(Champion::ZED), won))
+            .chain(
+                against_syndra
+                    .iter()
+                    .map(|&won| game(Champion::AHRI, Some(Champion::SYNDRA), won)),
+            )
+            .collect::<Vec<_>>();
+        matchups(&games)
+            .best_and_worst()
+            .map(|((best, _), (worst, _))| (*best, *worst))
+    }
+}
@@ -0,0 +1,142 @@
+//! Current streak, last play session and results of the recent games.
+
+use crate::{
+    display::{theme, DisplayableSection},
+    models::{
+        form::{self, GameOutcome, Streak},
+        matches::{GameResult, MatchInfo},
+    },
+};
+use lolfetch_color::ColoredString;
+use riven::{consts::Queue, models::summoner_v4::Summoner};
+
+/// Number of games in the recent form.
+const RECENT_GAMES: usize = 20;
+
+/// Losing streak from which the player is warned.
+const LOSING_STREAK_WARNING: u32 = 3;
+
+/// LP usually won or lost by a ranked game, used to estimate the LP of a session.
+const ESTIMATED_LP_PER_GAME: i32 = 20;
+
+pub struct RecentForm {
+    /// Games from the most recent, remakes excluded.
+    games: Vec<GameOutcome>,
+}
+
+impl RecentForm {
+    /// Only ranked solo games are used, `matches` being sorted from the most recent.
+    pub fn new(matches: &[MatchInfo], summoner: &Summoner) -> Self {
+        Self {
+            games: matches
+                .iter()
+                .filter(|game| game.info.queue_id == Queue::SUMMONERS_RIFT_5V5_RANKED_SOLO)
+                .filter_map(|game| GameOutcome::from_match_info(game, summoner))
+                .take(RECENT_GAMES)
+                .collect(),
+        }
+    }
+}
+
+impl DisplayableSection for RecentForm {
+    fn header(&self) -> Option<String> {
+        Some(format!("Recent Form (last {} games)", self.games.len()))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+        let result_color = |result| match result {
+            GameResult::Win => theme.win,
+            GameResult::Loss => theme.loss,
+        };
+
+        let mut body = Vec::new();
+
+        let Some(streak) = form::current_streak(&self.games) else {
+            body.push(ColoredString::from_unformatted_str("No recent games"));
+            return body;
+        };
+
+        // Current streak
+        let mut streak_string = ColoredString::from_unformatted_str("Streak: ");
+        let (word, plural) = match streak.result {
+            GameResult::Win => ("win", "wins"),
+            GameResult::Loss => ("loss", "losses"),
+        };
+        streak_string.push_str(
+            &format!(
+                "{} {}",
+                streak.games,
+                if streak.games == 1 { word } else { plural }
+            ),
+            Some(result_color(streak.result)),
+            None,
+        );
+        body.push(streak_string);
+
+        // Last session
+        if let Some(session) = form::sessions(&self.games).first() {
+            let wins = session
+                .iter()
+                .filter(|game| game.result == GameResult::Win)
+                .count() as i32;
+            let losses = session.len() as i32 - wins;
+            let lp = (wins - losses) * ESTIMATED_LP_PER_GAME;
+
+            let mut session_string = ColoredString::from_unformatted_str(&format!(
+                "Last session: {} games - ",
+                session.len()
+            ));
+            session_string.push_str(&format!("{wins}W"), Some(theme.win), None);
+            session_string.push_unformatted_str(" ");
+            session_string.push_str(&format!("{losses}L"), Some(theme.loss), None);
+            session_string.push_unformatted_str(" - ");
+            session_string.push_str(
+                &format!("~{lp:+} LP"),
+                Some(if lp >= 0 {
+                    theme.positive
+                } else {
+                    theme.negative
+                }),
+                None,
+            );
+            body.push(session_string);
+        }
+
+        // Results from the oldest to the most recent, so the form reads left to right
+        let wins = self
+            .games
+            .iter()
+            .filter(|game| game.result == GameResult::Win)
+            .count();
+        let mut form_string = ColoredString::from_unformatted_str("Form: ");
+        for game in self.games.iter().rev() {
+            let letter = match game.result {
+                GameResult::Win => "W",
+                GameResult::Loss => "L",
+            };
+            form_string.push_str(letter, Some(result_color(game.result)), None);
+        }
+        form_string.push_unformatted_str(&format!(
+            " - {:.0}% WR",
+            wins as f64 / self.games.len() as f64 * 100.0
+        ));
+        body.push(form_string);
+
+        if let Streak {
+            result: GameResult::Loss,
+            games,
+        } = streak
+        {
+            if games >= LOSING_STREAK_WARNING {
+                body.push(ColoredString::from_str(
+                    &format!("You're on a {games}-game losing streak, stop queueing!"),
+                    Some(theme.loss),
+                    None,
+                ));
+            }
+        }
+
+        body
+    }
+}
@@ -0,0 +1,130 @@
+//! Changes since the last refresh of the watch mode.
+
+use crate::{
+    api::Data as ApiData,
+    display::{theme, DisplayableSection},
+    models::{
+        matches::{GameResult, MatchPlayerInfo},
+        ranked::{self, RankedInfo},
+    },
+};
+use lolfetch_color::ColoredString;
+use riven::models::league_v4::LeagueEntry;
+use std::{collections::HashSet, time::Duration};
+
+pub struct RefreshSummary {
+    /// Games finished since the last refresh, from the most recent.
+    new_games: Vec<MatchPlayerInfo>,
+    /// LP won or lost since the last refresh, and the current rank.
+    lp_change: Option<(i32, RankedInfo)>,
+    /// Whether this is the first refresh, which has nothing to compare with.
+    first: bool,
+    interval: Duration,
+}
+
+impl RefreshSummary {
+    pub fn new(previous: Option<&ApiData>, current: &ApiData, interval: Duration) -> Self {
+        let Some(previous) = previous else {
+            return Self {
+                new_games: Vec::new(),
+                lp_change: None,
+                first: true,
+                interval,
+            };
+        };
+
+        let known = previous
+            .matches
+            .iter()
+            .flatten()
+            .map(|game| &game.id)
+            .collect::<HashSet<_>>();
+        let new_games = current
+            .matches
+            .iter()
+            .flatten()
+            .filter(|game| !known.contains(&game.id))
+            .filter_map(|game| MatchPlayerInfo::from_match_info(game, &current.summoner).ok())
+            .collect();
+
+        Self {
+            new_games,
+            lp_change: lp_change(previous.ranked.as_ref(), current.ranked.as_ref()),
+            first: false,
+            interval,
+        }
+    }
+}
+
+/// Returns the LP difference between two ranks, `None` if unchanged or unranked.
+fn lp_change(
+    previous: Option<&LeagueEntry>,
+    current: Option<&LeagueEntry>,
+) -> Option<(i32, RankedInfo)> {
+    let absolute_lp = |entry: &LeagueEntry| {
+        entry
+            .tier
+            .filter(|tier| tier.is_ranked())
+            .map(|tier| ranked::absolute_lp(tier, entry.rank, entry.league_points))
+    };
+    let (previous, current) = (previous?, current?);
+    let change = absolute_lp(current)? - absolute_lp(previous)?;
+
+    (change != 0)
+        .then(|| RankedInfo::from_entry(current.clone()))
+        .flatten()
+        .map(|rank| (change, rank))
+}
+
+impl DisplayableSection for RefreshSummary {
+    fn header(&self) -> Option<String> {
+        Some("Since last refresh".to_string())
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+        let mut body = Vec::new();
+
+        if !self.first && self.new_games.is_empty() && self.lp_change.is_none() {
+            body.push(ColoredString::from_unformatted_str("Nothing new"));
+        }
+
+        for game in &self.new_games {
+            let (result, color) = match game.game_result {
+                GameResult::Win => ("WIN", theme.win),
+                GameResult::Loss => ("LOSS", theme.loss),
+            };
+            let mut line = ColoredString::from_str("New game: ", Some(theme.accent), None);
+            line.push_str(result, Some(color), None);
+            line.push_unformatted_str(&format!(
+                " - {} - {} - {}",
+                game.champion.name().unwrap_or("Unknown"),
+                game.team_position,
+                game.kda
+            ));
+            body.push(line);
+        }
+
+        if let Some((change, rank)) = &self.lp_change {
+            let mut line = ColoredString::from_unformatted_str("LP: ");
+            line.push_str(
+                &format!("{change:+}"),
+                Some(if *change > 0 {
+                    theme.positive
+                } else {
+                    theme.negative
+                }),
+                None,
+            );
+            line.push_unformatted_str(&format!(" - now {rank}"));
+            body.push(line);
+        }
+
+        body.push(ColoredString::from_unformatted_str(&format!(
+            "Refreshing every {}s, Ctrl+C to quit",
+            self.interval.as_secs()
+        )));
+
+        body
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    data::fields,
+    display::{
+        template::{Field, Fields, Template},
+        theme,
+        utils::widgets,
+        DisplayableSection,
+    },
+    models::{
+        champion_stats::GameStats,
+        matches::{MatchInfo, MatchPlayerInfo, TeamPosition},
+    },
+};
+use lolfetch_color::ColoredString;
+use riven::models::summoner_v4::Summoner;
+use std::collections::HashMap;
+
+pub struct RoleStats {
+    /// Statistics of each played role, from the most played.
+    roles: Vec<(TeamPosition, GameStats)>,
+    games_processed: u32,
+    template: Template,
+}
+
+impl RoleStats {
+    pub const DEFAULT_TEMPLATE: &'static str =
+        "{role} - {games:>} Played ({share:>3}%) - {winrate:>3}% WR - {kda_ratio} - {cs_per_min} CS/M";
+
+    /// Fields available in the row template.
+    pub const FIELDS: &'static [&'static str] = &[
+        "role",
+        "games",
+        "share",
+        "share_bar",
+        "wins",
+        "losses",
+        "winrate",
+        "winrate_bar",
+        "kda_ratio",
+        "cs_per_min",
+        "kp",
+        "damage_share",
+        "dpm",
+        "gpm",
+        "vspm",
+        "control_wards",
+        "objective_participation",
+        "gd@<minute>",
+        "csd@<minute>",
+        "xpd@<minute>",
+        "lvld@<minute>",
+    ];
+
+    pub fn new(matches: &[MatchInfo], summoner: &Summoner, template: &Template) -> Self {
+        let match_infos = matches
+            .iter()
+            .map(|game| {
+                MatchPlayerInfo::from_match_info(game, summoner)
+                    .expect("Failed to get match player info")
+            })
+            .collect::<Vec<_>>();
+
+        Self::from_match_infos(&match_infos, template)
+    }
+
+    fn from_match_infos(match_infos: &[MatchPlayerInfo], template: &Template) -> Self {
+        let mut stats = HashMap::new();
+        for match_info in match_infos {
+            stats
+                .entry(match_info.team_position)
+                .or_insert_with(GameStats::new)
+                .add_game(match_info);
+        }
+
+        let mut roles = stats.into_iter().collect::<Vec<_>>();
+        roles.sort_by_key(|(role, stats)| (std::cmp::Reverse(stats.total_games()), *role));
+
+        Self {
+            roles,
+            games_processed: match_infos.len() as u32,
+            template: template.clone(),
+        }
+    }
+
+    /// Returns the most played role, if any game was played.
+    pub fn primary_role(&self) -> Option<TeamPosition> {
+        self.roles.first().map(|(role, _)| *role)
+    }
+
+    /// Returns the second most played role, if the player played more than one role.
+    pub fn secondary_role(&self) -> Option<TeamPosition> {
+        self.roles.get(1).map(|(role, _)| *role)
+    }
+
+    fn share(&self, stats: &GameStats) -> f64 {
+        f64::from(stats.total_games()) / f64::from(self.games_processed)
+    }
+}
+
+impl DisplayableSection for RoleStats {
+    fn header(&self) -> Option<String> {
+        Some(format!("Role Stats (last {} games)", self.games_processed))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        const BAR_WIDTH: usize = 10;
+
+        let theme = theme::current();
+
+        let mut body = Vec::new();
+
+        if let Some(primary) = self.primary_role() {
+            let mut main_roles = ColoredString::from_unformatted_str("Main role: ");
+            main_roles.push_str(&primary.to_string(), Some(theme.accent), None);
+            if let Some(secondary) = self.secondary_role() {
+                main_roles.push_unformatted_str(" - Secondary: ");
+                main_roles.push_str(&secondary.to_string(), Some(theme.accent), None);
+            }
+            body.push(main_roles);
+        }
+
+        let rows = self
+            .roles
+            .iter()
+            .map(|(role, stats)| {
+                let mut fields = Fields::from([
+                    ("role", Field::text(role.to_string())),
+                    (
+                        "share",
+                        Field::text(format!("{:.0}", self.share(stats) * 100.0)),
+                    ),
+                    (
+                        "share_bar",
+                        Field::widget(widgets::progress_bar(
+                            self.share(stats),
+                            BAR_WIDTH,
+                            theme.accent,
+                        )),
+                    ),
+                ]);
+                fields::insert_results(&mut fields, stats.wins(), stats.losses());
+                fields::insert_kda_cs(&mut fields, stats);
+                fields::insert_performance(&mut fields, stats, true);
+                fields::insert_lane_diffs(&mut fields, stats.lane_diffs().averages(), true);
+                fields
+            })
+            .collect::<Vec<_>>();
+
+        body.extend(self.template.render(&rows));
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::matches::GameResult;
+    use riven::consts::Champion;
+    use test_case::test_case;
+
+    fn game(team_position: TeamPosition) -> MatchPlayerInfo {
+        MatchPlayerInfo::test(Champion::AHRI, team_position, GameResult::Win)
+    }
+
+    #[test_case(&[] => (None, None) ; "no games")]
+    #[test_case(&[TeamPosition::Mid] => (Some(TeamPosition::Mid), None) ; "single role")]
+    #[test_case(
+        &[TeamPosition::Top, TeamPosition::Mid, TeamPosition::Mid, TeamPosition::Jungle, TeamPosition::Top, TeamPosition::Mid]
+        => (Some(TeamPosition::Mid), Some(TeamPosition::Top)) ; "most played"
+    )]
+    #[test_case(
+        &[TeamPosition::Support, TeamPosition::Jungle]
+        => (Some(TeamPosition::Jungle), Some(TeamPosition::Support)) ; "ties follow the role order"
+    )]
+    fn test_main_roles(roles: &[TeamPosition]) -> (Option<TeamPosition>, Option<TeamPosition>) {
+        let games = roles.iter().copied().map(game).collect::<Vec<_>>();
+        let template = Template::parse(RoleStats::DEFAULT_TEMPLATE).unwrap();
+        let stats = RoleStats::from_match_infos(&games, &template);
+        (stats.primary_role(), stats.secondary_role())
+    }
+}
@@ -0,0 +1,391 @@
+//! Scoreboard of a single game: the participants and objectives of both teams,
+//! and the gold difference of the summoner with their lane opponent.
+
+use super::fields;
+use crate::{
+    api::tooling::static_data::StaticNames,
+    display::{
+        template::{Field, Fields, Template},
+        theme,
+        utils::widgets,
+        DisplayableSection,
+    },
+    models::{
+        lane_diff::{LaneDiffGetter, Minute},
+        loadout::Loadout,
+        matches::{
+            queue_name, Kda, LaneOpponentGetter, MatchInfo, ParticipantGetter, TeamPosition,
+        },
+    },
+};
+use lolfetch_color::ColoredString;
+use riven::{
+    consts::Team,
+    models::{
+        match_v5::{Objectives, Participant},
+        summoner_v4::Summoner,
+    },
+};
+
+/// Number of rows above (and below) zero in the gold difference chart.
+const CHART_HALF_HEIGHT: usize = 3;
+
+pub struct Scoreboard {
+    game: Box<MatchInfo>,
+    /// PUUID of the summoner, whose row is highlighted.
+    puuid: String,
+    /// Gold difference of the summoner with their lane opponent at each minute.
+    gold_diffs: Vec<(Minute, i32)>,
+    /// Champion of the lane opponent of the summoner.
+    opponent: Option<String>,
+    names: &'static StaticNames,
+    template: Template,
+}
+
+impl Scoreboard {
+    pub const DEFAULT_TEMPLATE: &'static str = "{riot_id:<}  {champion:<}  {kda:>}  {cs:>} CS  {gold:>} gold  {damage:>} dmg  {vision:>} vision  {items}";
+
+    /// Fields available in the row template.
+    pub const FIELDS: &'static [&'static str] = &[
+        "riot_id",
+        "champion",
+        "position",
+        "level",
+        "kda",
+        "kills",
+        "deaths",
+        "assists",
+        "kda_ratio",
+        "kp",
+        "cs",
+        "cs_per_min",
+        "gold",
+        "damage",
+        "damage_share",
+        "vision",
+        "control_wards",
+        "items",
+        "runes",
+        "spells",
+    ];
+
+    pub fn new(
+        game: MatchInfo,
+        summoner: &Summoner,
+        names: &'static StaticNames,
+        template: &Template,
+    ) -> Self {
+        let gold_diffs = game
+            .get_lane_diffs(summoner)
+            .into_iter()
+            .map(|(minute, diff)| (minute, diff.gold))
+            .collect();
+        let opponent = game
+            .info
+            .get_participant(summoner)
+            .ok()
+            .and_then(|participant| game.info.get_lane_opponent(participant))
+            .map(champion_name);
+
+        Self {
+            puuid: summoner.puuid.clone(),
+            gold_diffs,
+            opponent,
+            game: Box::new(game),
+            names,
+            template: template.clone(),
+        }
+    }
+
+    fn row(&self, participant: &Participant) -> Fields {
+        let theme = theme::current();
+        let info = &self.game.info;
+        let minutes = info.game_duration as f64 / 60.0;
+        let teammates = || {
+            info.participants
+                .iter()
+                .filter(|p| p.team_id == participant.team_id)
+        };
+        let team_kills: i32 = teammates().map(|p| p.kills).sum();
+        let team_damage: i32 = teammates().map(|p| p.total_damage_dealt_to_champions).sum();
+
+        let riot_id = participant
+            .riot_id_game_name
+            .clone()
+            .filter(|name| !name.is_empty())
+            .map_or_else(
+                || participant.summoner_name.clone(),
+                |name| format!("{name}#{}", participant.riot_id_tagline),
+            );
+        let is_summoner = participant.puuid == self.puuid;
+        let kda = Kda(participant.kills, participant.deaths, participant.assists);
+        let cs = participant.total_minions_killed + participant.neutral_minions_killed;
+        let loadout = Loadout::from_participant(participant);
+        let percentage =
+            |part: i32, total: i32| fields::percentage(f64::from(part) / f64::from(total));
+
+        let mut fields = Fields::from([
+            (
+                "riot_id",
+                Field::colored(riot_id, is_summoner.then_some(theme.accent)),
+            ),
+            ("champion", Field::text(champion_name(participant))),
+            ("level", Field::text(participant.champ_level.to_string())),
+            ("kda", Field::text(kda.to_string())),
+            ("kills", Field::text(participant.kills.to_string())),
+            ("deaths", Field::text(participant.deaths.to_string())),
+            ("assists", Field::text(participant.assists.to_string())),
+            ("kda_ratio", Field::text(fields::kda_text(kda.get_kda()))),
+            ("cs", Field::text(cs.to_string())),
+            (
+                "cs_per_min",
+                Field::text(format!("{:.1}", f64::from(cs) / minutes.max(1.0))),
+            ),
+            ("gold", Field::text(participant.gold_earned.to_string())),
+            (
+                "damage",
+                Field::text(participant.total_damage_dealt_to_champions.to_string()),
+            ),
+            ("vision", Field::text(participant.vision_score.to_string())),
+            (
+                "control_wards",
+                Field::text(participant.vision_wards_bought_in_game.to_string()),
+            ),
+            (
+                "items",
+                Field::text(fields::items_text(self.names, &loadout.items)),
+            ),
+            (
+                "spells",
+                Field::text(
+                    loadout
+                        .summoner_spells
+                        .map(|spell| self.names.summoner_spell_name(spell))
+                        .join("/"),
+                ),
+            ),
+        ]);
+
+        if let Ok(position) = TeamPosition::try_from(participant.team_position.clone()) {
+            fields.insert("position", Field::text(position.to_string()));
+        }
+        if team_kills > 0 {
+            fields.insert(
+                "kp",
+                percentage(participant.kills + participant.assists, team_kills),
+            );
+        }
+        if team_damage > 0 {
+            fields.insert(
+                "damage_share",
+                percentage(participant.total_damage_dealt_to_champions, team_damage),
+            );
+        }
+        if let Some(runes) = loadout.runes {
+            fields.insert("runes", Field::text(fields::runes_text(self.names, runes)));
+        }
+
+        fields
+    }
+
+    /// Returns the label of a team: side, result, kills and objectives.
+    fn team_label(&self, side: Team) -> ColoredString {
+        let theme = theme::current();
+        let mut label = ColoredString::from_str(
+            if side == Team::BLUE {
+                "Blue side"
+            } else {
+                "Red side"
+            },
+            Some(theme.accent),
+            None,
+        );
+
+        let Some(team) = self
+            .game
+            .info
+            .teams
+            .iter()
+            .find(|team| team.team_id == side)
+        else {
+            return label;
+        };
+        label.push_unformatted_str(" - ");
+        if team.win {
+            label.push_str("Victory", Some(theme.win), None);
+        } else {
+            label.push_str("Defeat", Some(theme.loss), None);
+        }
+        label.push_unformatted_str(&format!(
+            " - {} kills - {}",
+            team.objectives.champion.kills,
+            objectives_text(&team.objectives)
+        ));
+
+        label
+    }
+
+    fn gold_chart(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+        let Some(max) = self.gold_diffs.iter().map(|(_, gold)| gold.abs()).max() else {
+            return Vec::new();
+        };
+
+        let mut body = vec![ColoredString::from_str(
+            &format!(
+                "Gold difference with {}",
+                self.opponent.as_deref().unwrap_or("the lane opponent")
+            ),
+            Some(theme.accent),
+            None,
+        )];
+
+        // Largest difference on the first row, its opposite on the last one
+        let labels = [format!("+{max}"), format!("-{max}")];
+        let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+        let values = self
+            .gold_diffs
+            .iter()
+            .map(|(_, gold)| f64::from(*gold))
+            .collect::<Vec<_>>();
+        let chart =
+            widgets::diverging_chart(&values, CHART_HALF_HEIGHT, theme.positive, theme.negative);
+        for (row, chart_row) in chart.into_iter().enumerate() {
+            let label = match row {
+                0 => labels[0].as_str(),
+                row if row == 2 * CHART_HALF_HEIGHT - 1 => labels[1].as_str(),
+                _ => "",
+            };
+            let mut line = ColoredString::from_unformatted_str(&format!("{label:>label_width$} │"));
+            line.join(&chart_row);
+            body.push(line);
+        }
+
+        // Differences at the usual checkpoints and at the end of the game
+        let mut minutes = vec![10, 15, 20];
+        if let Some(&(last, _)) = self.gold_diffs.last() {
+            if !minutes.contains(&last) {
+                minutes.push(last);
+            }
+        }
+        let mut summary = ColoredString::new();
+        for (minute, gold) in minutes
+            .into_iter()
+            .filter_map(|minute| self.gold_diffs.iter().find(|(m, _)| *m == minute).copied())
+        {
+            if !summary.is_empty() {
+                summary.push_unformatted_str(" - ");
+            }
+            summary.push_unformatted_str(&format!("@{minute}: "));
+            summary.push_str(
+                &format!("{gold:+}"),
+                Some(if gold >= 0 {
+                    theme.positive
+                } else {
+                    theme.negative
+                }),
+                None,
+            );
+        }
+        body.push(summary);
+
+        body
+    }
+}
+
+impl DisplayableSection for Scoreboard {
+    fn header(&self) -> Option<String> {
+        let duration = self.game.info.game_duration;
+        Some(format!(
+            "{} - {} - {:02}:{:02}",
+            self.game.id,
+            queue_name(Some(self.game.info.queue_id)),
+            duration / 60,
+            duration % 60
+        ))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        // Both teams are rendered together so that their columns are aligned
+        let (blue, red): (Vec<_>, Vec<_>) = self
+            .game
+            .info
+            .participants
+            .iter()
+            .partition(|participant| participant.team_id == Team::BLUE);
+        let rows = blue
+            .iter()
+            .chain(&red)
+            .map(|participant| self.row(participant))
+            .collect::<Vec<_>>();
+        let mut rendered = self.template.render(&rows);
+        let red_rows = rendered.split_off(blue.len());
+
+        let mut body = Vec::new();
+        for (side, rows) in [(Team::BLUE, rendered), (Team::RED, red_rows)] {
+            if rows.is_empty() {
+                continue;
+            }
+            body.push(self.team_label(side));
+            body.extend(rows);
+        }
+
+        body.extend(self.gold_chart());
+        body
+    }
+}
+
+fn champion_name(participant: &Participant) -> String {
+    participant
+        .champion()
+        .ok()
+        .and_then(|champion| champion.name())
+        .map_or_else(|| participant.champion_name.clone(), ToString::to_string)
+}
+
+/// Returns the objectives taken by a team, e.g. `9 towers, 2 inhibitors, 3 dragons`.
+fn objectives_text(objectives: &Objectives) -> String {
+    [
+        (objectives.tower.kills, "tower"),
+        (objectives.inhibitor.kills, "inhibitor"),
+        (objectives.dragon.kills, "dragon"),
+        (objectives.baron.kills, "baron"),
+        (objectives.rift_herald.kills, "herald"),
+        (
+            objectives.horde.as_ref().map_or(0, |horde| horde.kills),
+            "voidgrub",
+        ),
+    ]
+    .into_iter()
+    .map(|(kills, name)| format!("{kills} {name}{}", if kills == 1 { "" } else { "s" }))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use riven::models::match_v5::Objective;
+
+    #[test]
+    fn test_objectives_text() {
+        let objective = |kills| Objective {
+            first: false,
+            kills,
+        };
+        let objectives = Objectives {
+            baron: objective(1),
+            champion: objective(25),
+            dragon: objective(3),
+            horde: None,
+            inhibitor: objective(0),
+            rift_herald: objective(1),
+            tower: objective(9),
+        };
+
+        assert_eq!(
+            objectives_text(&objectives),
+            "9 towers, 0 inhibitors, 3 dragons, 1 baron, 1 herald, 0 voidgrubs"
+        );
+    }
+}
@@ -3,13 +3,26 @@
 use crate::{
     api::account::RiotId,
     display::{
-        utils::{colors::RankColorGetter, generate_loading_bar},
+        theme,
+        utils::{colors::RankColorGetter, generate_loading_bar, widgets},
         DisplayableSection,
     },
-    models::ranked::RankedInfo,
+    models::{
+        ranked::RankedInfo,
+        trends::{self, GameSample, Metric, Trend},
+    },
 };
 use lolfetch_color::ColoredString;
 use riven::models::league_v4::LeagueEntry;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Number of points of the trend sparklines, the most recent ones being kept.
+const MAX_TREND_POINTS: usize = 30;
+
+/// Number of weeks displayed in the weekly aggregates.
+const MAX_WEEKS: usize = 4;
+
+const WINRATE_BAR_WIDTH: i32 = 30;
 
 /// Summoner information.
 pub struct Summoner {
@@ -19,6 +32,18 @@ pub struct Summoner {
     /// It is optional because this struct can be used to display information about a summoner
     /// without a rank.
     pub ranked: Option<RankedInfo>,
+
+    /// Performance over the recent games, displayed below the rank.
+    pub trends: Option<Trends>,
+}
+
+/// Games used to follow the performance over time.
+pub struct Trends {
+    /// Games from the oldest.
+    pub samples: Vec<GameSample>,
+
+    /// Number of games of the rolling averages.
+    pub window: usize,
 }
 
 impl Summoner {
@@ -29,8 +54,85 @@ impl Summoner {
             riot_id: riot_id.clone(),
             // Filter out unranked tiers (since it is useless to display them).
             ranked: ranked.filter(|r| r.tier != riven::consts::Tier::UNRANKED),
+            trends: None,
         }
     }
+
+    /// Displays the rolling averages, trends and weekly aggregates of the games.
+    pub fn with_trends(mut self, samples: Vec<GameSample>, window: usize) -> Self {
+        self.trends = Some(Trends { samples, window });
+        self
+    }
+}
+
+impl Trends {
+    fn body(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+
+        if self.samples.is_empty() {
+            return Vec::new();
+        }
+
+        let mut body = vec![ColoredString::from_unformatted_str(&format!(
+            "Trends (last {} games, average of {}):",
+            self.samples.len(),
+            self.window
+        ))];
+
+        for metric in Metric::ALL {
+            let rolling = trends::rolling_average(&metric.values(&self.samples), self.window);
+            let Some(&current) = rolling.last() else {
+                continue;
+            };
+            let trend = Trend::of(&rolling, metric);
+
+            let mut line =
+                ColoredString::from_unformatted_str(&format!("{:<6}", metric.to_string()));
+            line.join(&widgets::sparkline(
+                &rolling[rolling.len().saturating_sub(MAX_TREND_POINTS)..],
+                Some(theme.accent),
+            ));
+            line.push_unformatted_str(" ");
+            line.push_str(
+                &trend.arrow().to_string(),
+                match trend {
+                    Trend::Improving => Some(theme.positive),
+                    Trend::Declining => Some(theme.negative),
+                    Trend::Stable => None,
+                },
+                None,
+            );
+            line.push_unformatted_str(&format!(" {}", metric.format(current)));
+            body.push(line);
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis() as i64);
+        let weeks = trends::weekly(&self.samples, now);
+        for week in weeks.iter().rev().take(MAX_WEEKS) {
+            let label = match week.weeks_ago {
+                0 => "This week".to_string(),
+                1 => "Last week".to_string(),
+                weeks => format!("{weeks} weeks ago"),
+            };
+            let averages = Metric::ALL
+                .iter()
+                .filter_map(|&metric| {
+                    week.average(metric)
+                        .map(|average| format!("{} {metric}", metric.format(average)))
+                })
+                .collect::<Vec<_>>()
+                .join(" - ");
+            let games = week.samples.len();
+            body.push(ColoredString::from_unformatted_str(&format!(
+                "{label}: {games} game{} - {averages}",
+                if games == 1 { "" } else { "s" }
+            )));
+        }
+
+        body
+    }
 }
 
 impl DisplayableSection for Summoner {
@@ -39,8 +141,6 @@ impl DisplayableSection for Summoner {
     }
 
     fn body(&self) -> Vec<ColoredString> {
-        const WINRATE_BAR_WIDTH: i32 = 30;
-
         let mut body = Vec::new();
 
         // Summoner name
@@ -77,11 +177,9 @@ impl DisplayableSection for Summoner {
             ));
 
             winrate_string.push_str(
-                &format!(
-                    " {:.1}%",
-                    ranked
-                        .get_winrate()
-                        .expect("The player does not have a winrate (so no games)")
+                &ranked.get_winrate().map_or_else(
+                    || " -".to_string(),
+                    |winrate| format!(" {:.1}%", winrate * 100.0),
                 ),
                 Some(rank_color),
                 None,
@@ -89,8 +187,55 @@ impl DisplayableSection for Summoner {
             winrate_string.push_unformatted_str(&format!(" ({}W/{}L)", ranked.wins, ranked.losses));
 
             body.push(ranked_string);
+            body.push(winrate_string);
+
+            // Progress to the next division, apex tiers have no divisions
+            if !ranked.tier.is_apex() {
+                let mut progress_string = ColoredString::from_unformatted_str(&" ".repeat(6));
+                progress_string.join(&widgets::lp_progress(
+                    ranked.lp,
+                    WINRATE_BAR_WIDTH as usize,
+                    rank_color,
+                ));
+                body.push(progress_string);
+            }
+        }
+
+        if let Some(trends) = &self.trends {
+            body.extend(trends.body());
         }
 
         body
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use riven::consts::{Division, QueueType, Tier};
+    use test_case::test_case;
+
+    #[test_case(52, 48 => " 52.0% (52W/48L)" ; "winrate as a percentage")]
+    #[test_case(0, 0 => " - (0W/0L)" ; "no games")]
+    fn test_winrate(wins: i32, losses: i32) -> String {
+        let summoner = Summoner {
+            riot_id: RiotId::from_str("abc#1234").expect("Riot ID should be valid"),
+            ranked: Some(RankedInfo {
+                queue: QueueType::RANKED_SOLO_5x5,
+                tier: Tier::GOLD,
+                division: Some(Division::II),
+                lp: 45,
+                wins,
+                losses,
+            }),
+            trends: None,
+        };
+
+        // Indentation and winrate bar skipped
+        summoner.body()[2]
+            .iter()
+            .skip(6 + WINRATE_BAR_WIDTH as usize)
+            .map(|c| c.character())
+            .collect()
+    }
+}
@@ -0,0 +1,279 @@
+//! Rank, form and champions of the players of a roster, and how the team is doing as a whole.
+
+use crate::{
+    api::account::RiotId,
+    data::fields,
+    display::{
+        template::{Field, Fields, Template},
+        theme,
+        utils::colors::RankColorGetter,
+        DisplayableSection,
+    },
+    models::{
+        form::Streak,
+        matches::{GameResult, TeamPosition},
+        ranked,
+        summary::PlayerSummary,
+    },
+};
+use lolfetch_color::ColoredString;
+
+/// Number of champions listed for each player.
+const MAX_CHAMPIONS: usize = 3;
+
+/// Streak from which a player is pointed out in the team summary.
+const NOTABLE_STREAK: u32 = 3;
+
+/// Roles of a team, in the order of the lanes.
+const ROLES: [TeamPosition; 5] = [
+    TeamPosition::Top,
+    TeamPosition::Jungle,
+    TeamPosition::Mid,
+    TeamPosition::Bot,
+    TeamPosition::Support,
+];
+
+pub struct Team {
+    name: String,
+    players: Vec<PlayerSummary>,
+    /// Players that could not be fetched.
+    missing: Vec<RiotId>,
+    games: i32,
+    template: Template,
+}
+
+impl Team {
+    pub const DEFAULT_TEMPLATE: &'static str =
+        "{riot_id:<}  {rank:<}  {form:<}  {winrate:>3}% WR  {role:<}  {champions}";
+
+    /// Fields available in the row template.
+    pub const FIELDS: &'static [&'static str] = &[
+        "riot_id",
+        "rank",
+        "short_rank",
+        "lp",
+        "form",
+        "streak",
+        "games",
+        "wins",
+        "losses",
+        "winrate",
+        "kda_ratio",
+        "role",
+        "champions",
+    ];
+
+    pub const fn new(
+        name: String,
+        players: Vec<PlayerSummary>,
+        missing: Vec<RiotId>,
+        games: i32,
+        template: Template,
+    ) -> Self {
+        Self {
+            name,
+            players,
+            missing,
+            games,
+            template,
+        }
+    }
+
+    fn row(player: &PlayerSummary) -> Fields {
+        let theme = theme::current();
+        let result_color = |result: GameResult| match result {
+            GameResult::Win => theme.win,
+            GameResult::Loss => theme.loss,
+        };
+        let rank_color = player
+            .ranked
+            .as_ref()
+            .and_then(|ranked| ranked.tier.get_rank_color());
+
+        // Results from the oldest to the most recent, so the form reads left to right
+        let mut form = ColoredString::new();
+        for outcome in player.outcomes.iter().rev() {
+            let letter = match outcome.result {
+                GameResult::Win => "W",
+                GameResult::Loss => "L",
+            };
+            form.push_str(letter, Some(result_color(outcome.result)), None);
+        }
+
+        let champions = player
+            .champions
+            .iter()
+            .take(MAX_CHAMPIONS)
+            .map(|(champion, _)| champion.name().unwrap_or("Unknown"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let stats = &player.stats;
+
+        let mut fields = Fields::from([
+            ("riot_id", Field::text(player.riot_id.to_string())),
+            (
+                "rank",
+                Field::colored(
+                    player
+                        .ranked
+                        .as_ref()
+                        .map_or_else(|| "Unranked".to_string(), ToString::to_string),
+                    rank_color,
+                ),
+            ),
+            (
+                "short_rank",
+                Field::colored(
+                    player
+                        .ranked
+                        .as_ref()
+                        .map_or_else(|| "-".to_string(), ranked::RankedInfo::short_rank),
+                    rank_color,
+                ),
+            ),
+            ("form", Field::widget(form)),
+            (
+                "role",
+                Field::text(
+                    player
+                        .main_role()
+                        .map_or_else(|| "-".to_string(), |role| role.to_string()),
+                ),
+            ),
+            ("champions", Field::text(champions)),
+        ]);
+
+        if let Some(ranked) = &player.ranked {
+            fields.insert("lp", Field::text(ranked.lp.to_string()));
+        }
+        fields::insert_results(&mut fields, stats.wins(), stats.losses());
+        if stats.total_games() > 0 {
+            fields.insert("kda_ratio", Field::text(fields::kda_text(stats.kda())));
+        }
+        if let Some(Streak { result, games }) = player.streak() {
+            let letter = match result {
+                GameResult::Win => "W",
+                GameResult::Loss => "L",
+            };
+            fields.insert(
+                "streak",
+                Field::colored(format!("{games}{letter}"), Some(result_color(result))),
+            );
+        }
+
+        fields
+    }
+
+    /// Returns the lines describing the team as a whole.
+    fn summary(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+        let mut summary = Vec::new();
+
+        let ranks = self
+            .players
+            .iter()
+            .filter_map(|player| player.ranked.as_ref())
+            .map(ranked::RankedInfo::absolute_lp)
+            .collect::<Vec<_>>();
+        if !ranks.is_empty() {
+            let average = ranks.iter().sum::<i32>() / ranks.len() as i32;
+            summary.push(ColoredString::from_unformatted_str(&format!(
+                "Average rank: {} ({}/{} ranked)",
+                ranked::format_absolute_lp(average),
+                ranks.len(),
+                self.players.len()
+            )));
+        }
+
+        let (wins, losses) = self.players.iter().fold((0, 0), |(wins, losses), player| {
+            (wins + player.stats.wins(), losses + player.stats.losses())
+        });
+        if wins + losses > 0 {
+            summary.push(ColoredString::from_unformatted_str(&format!(
+                "Recent games: {wins}W/{losses}L - {:.0}% WR",
+                f64::from(wins) / f64::from(wins + losses) * 100.0
+            )));
+        }
+
+        // Main role of each player, and the roles nobody mains
+        let mut roles = ColoredString::from_unformatted_str("Roles:");
+        for role in ROLES {
+            let mains = self
+                .players
+                .iter()
+                .filter(|player| player.main_role() == Some(role))
+                .count();
+            let color = match mains {
+                0 => Some(theme.negative),
+                1 => Some(theme.positive),
+                _ => None,
+            };
+            roles.push_str(&format!(" {role}"), color, None);
+            if mains > 1 {
+                roles.push_unformatted_str(&format!(" x{mains}"));
+            }
+        }
+        summary.push(roles);
+
+        for player in &self.players {
+            match player.streak() {
+                Some(Streak {
+                    result: GameResult::Win,
+                    games,
+                }) if games >= NOTABLE_STREAK => {
+                    summary.push(ColoredString::from_str(
+                        &format!("{} is on a {games} win streak", player.riot_id),
+                        Some(theme.positive),
+                        None,
+                    ));
+                }
+                Some(Streak {
+                    result: GameResult::Loss,
+                    games,
+                }) if games >= NOTABLE_STREAK => {
+                    summary.push(ColoredString::from_str(
+                        &format!("{} is on a {games} loss streak", player.riot_id),
+                        Some(theme.negative),
+                        None,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        if !self.missing.is_empty() {
+            summary.push(ColoredString::from_str(
+                &format!(
+                    "Could not fetch: {}",
+                    self.missing
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                Some(theme.negative),
+                None,
+            ));
+        }
+
+        summary
+    }
+}
+
+impl DisplayableSection for Team {
+    fn header(&self) -> Option<String> {
+        Some(format!(
+            "Team {} (last {} ranked games)",
+            self.name, self.games
+        ))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        let rows = self.players.iter().map(Self::row).collect::<Vec<_>>();
+
+        let mut body = self.template.render(&rows);
+        body.push(ColoredString::new());
+        body.extend(self.summary());
+        body
+    }
+}
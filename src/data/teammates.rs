@@ -0,0 +1,94 @@
+//! Players the summoner often plays with, and how the games with them went.
+
+use crate::{
+    data::fields,
+    display::{
+        template::{Field, Fields, Template},
+        DisplayableSection,
+    },
+    models::{
+        matches::MatchInfo,
+        teammates::{Teammate, Teammates},
+    },
+};
+use lolfetch_color::ColoredString;
+use riven::models::summoner_v4::Summoner;
+
+/// Games needed together for a player to be considered a frequent teammate.
+const MIN_GAMES_TOGETHER: u32 = 2;
+
+/// Number of teammates displayed.
+const MAX_TEAMMATES: usize = 5;
+
+pub struct FrequentTeammates {
+    /// Most frequent teammates, from the most frequent.
+    teammates: Vec<Teammate>,
+    games_processed: usize,
+    template: Template,
+}
+
+impl FrequentTeammates {
+    pub const DEFAULT_TEMPLATE: &'static str =
+        "{riot_id:<} - {games:>} Games - {winrate:>3}% WR - {roles}";
+
+    /// Fields available in the row template.
+    pub const FIELDS: &'static [&'static str] = &[
+        "riot_id",
+        "games",
+        "wins",
+        "losses",
+        "winrate",
+        "winrate_bar",
+        "roles",
+    ];
+
+    pub fn new(matches: &[MatchInfo], summoner: &Summoner, template: &Template) -> Self {
+        let mut teammates = Teammates::from_matches(matches, summoner).frequent(MIN_GAMES_TOGETHER);
+        teammates.truncate(MAX_TEAMMATES);
+
+        Self {
+            teammates,
+            games_processed: matches.len(),
+            template: template.clone(),
+        }
+    }
+}
+
+impl DisplayableSection for FrequentTeammates {
+    fn header(&self) -> Option<String> {
+        Some(format!(
+            "Frequent Teammates (last {} games)",
+            self.games_processed
+        ))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        if self.teammates.is_empty() {
+            return vec![ColoredString::from_unformatted_str(
+                "No player found in several of your games",
+            )];
+        }
+
+        let rows = self
+            .teammates
+            .iter()
+            .map(|teammate| {
+                let roles = teammate
+                    .positions()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("/");
+
+                let mut fields = Fields::from([
+                    ("riot_id", Field::text(&teammate.riot_id)),
+                    ("roles", Field::text(roles)),
+                ]);
+                fields::insert_results(&mut fields, teammate.wins, teammate.losses);
+                fields
+            })
+            .collect::<Vec<_>>();
+
+        self.template.render(&rows)
+    }
+}
@@ -0,0 +1,190 @@
+//! First blood involvement, deaths and objective control, from the events of the timelines.
+
+use crate::{
+    api::tooling::static_data::StaticNames,
+    display::{theme, utils::widgets, DisplayableSection},
+    models::{
+        matches::MatchInfo,
+        timeline::{
+            EventStats, GameEvents, Objective, DEATH_PERIODS, DEATH_PERIOD_MINUTES,
+            EARLY_GAME_MINUTES,
+        },
+    },
+};
+use lolfetch_color::ColoredString;
+use riven::models::summoner_v4::Summoner;
+
+/// Width of the bars of the death timing distribution.
+const BAR_WIDTH: usize = 12;
+
+/// Size of the death map, a cell being about twice as high as wide.
+const MAP_WIDTH: usize = 30;
+const MAP_HEIGHT: usize = 15;
+
+pub struct TimelineAnalysis {
+    stats: EventStats,
+}
+
+impl TimelineAnalysis {
+    pub fn new(matches: &[MatchInfo], summoner: &Summoner, names: &StaticNames) -> Self {
+        let mut stats = EventStats::default();
+        for game in matches {
+            if let Some(events) = GameEvents::from_match_info(game, summoner) {
+                stats.add_game(&events, game.info.map_id, |item| names.is_core_item(item));
+            }
+        }
+
+        Self { stats }
+    }
+
+    /// Returns a value averaged over the games.
+    fn per_game(&self, value: u32) -> f64 {
+        f64::from(value) / f64::from(self.stats.games)
+    }
+
+    fn death_timings(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+        let per_game = self
+            .stats
+            .death_periods
+            .iter()
+            .map(|&deaths| self.per_game(deaths))
+            .collect::<Vec<_>>();
+        let max = per_game.iter().copied().fold(0.0, f64::max);
+
+        per_game
+            .iter()
+            .enumerate()
+            .map(|(period, deaths)| {
+                let start = period as i64 * DEATH_PERIOD_MINUTES;
+                let label = if period as i64 == DEATH_PERIODS {
+                    format!("{start}+")
+                } else {
+                    format!("{start}-{}", start + DEATH_PERIOD_MINUTES)
+                };
+                let mut line = ColoredString::from_unformatted_str(&format!("{label:>6} min "));
+                line.join(&widgets::horizontal_bar(
+                    *deaths,
+                    max,
+                    BAR_WIDTH,
+                    theme.negative,
+                ));
+                line.push_unformatted_str(&format!(" {deaths:.1}"));
+                line
+            })
+            .collect()
+    }
+
+    fn death_map(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+        let border = |left: char, right: char| {
+            ColoredString::from_unformatted_str(&format!("{left}{}{right}", "─".repeat(MAP_WIDTH)))
+        };
+
+        let mut map = vec![border('┌', '┐')];
+        for row in widgets::heatmap(&self.stats.death_map(MAP_WIDTH, MAP_HEIGHT), theme.negative) {
+            let mut line = ColoredString::from_unformatted_str("│");
+            line.join(&row);
+            line.push_unformatted_str("│");
+            map.push(line);
+        }
+        map.push(border('└', '┘'));
+
+        map
+    }
+}
+
+impl DisplayableSection for TimelineAnalysis {
+    fn header(&self) -> Option<String> {
+        Some(format!("Timeline ({} games)", self.stats.games))
+    }
+
+    fn body(&self) -> Vec<ColoredString> {
+        let theme = theme::current();
+        let stats = &self.stats;
+
+        if stats.games == 0 {
+            return vec![ColoredString::from_unformatted_str(
+                "No timeline in the games",
+            )];
+        }
+
+        let mut body = Vec::new();
+
+        if let Some(participation) = stats.first_blood_participation() {
+            body.push(ColoredString::from_unformatted_str(&format!(
+                "First blood: {:.0}% participation ({} kills, {} assists), died first in {} games",
+                participation * 100.0,
+                stats.first_blood_kills,
+                stats.first_blood_assists,
+                stats.first_blood_deaths
+            )));
+        }
+
+        body.push(ColoredString::from_unformatted_str(&format!(
+            "Deaths: {:.1} per game, {:.1} before {EARLY_GAME_MINUTES} min",
+            self.per_game(stats.deaths),
+            self.per_game(stats.early_deaths)
+        )));
+        body.extend(self.death_timings());
+
+        let objectives = Objective::ALL
+            .into_iter()
+            .filter_map(|objective| Some((objective, stats.objective_control(objective)?)))
+            .collect::<Vec<_>>();
+        if !objectives.is_empty() {
+            body.push(ColoredString::from_str(
+                "First objectives taken by the team",
+                Some(theme.accent),
+                None,
+            ));
+        }
+        for (objective, (share, time)) in objectives {
+            let mut line =
+                ColoredString::from_unformatted_str(&format!("{:>10} ", objective.name()));
+            line.push_str(
+                &format!("{:>3.0}%", share * 100.0),
+                Some(if share >= 0.5 {
+                    theme.positive
+                } else {
+                    theme.negative
+                }),
+                None,
+            );
+            line.push_unformatted_str(&format!(" - at {} on average", game_time(time)));
+            body.push(line);
+        }
+
+        body.push(ColoredString::from_unformatted_str(&format!(
+            "Wards: {:.1} placed, {:.1} destroyed per game",
+            self.per_game(stats.wards_placed),
+            self.per_game(stats.wards_killed)
+        )));
+
+        if !stats.first_core_items.is_empty() {
+            let average =
+                stats.first_core_items.iter().sum::<i64>() / stats.first_core_items.len() as i64;
+            body.push(ColoredString::from_unformatted_str(&format!(
+                "First core item: {} on average",
+                game_time(average)
+            )));
+        }
+
+        if !stats.death_positions.is_empty() {
+            body.push(ColoredString::from_str(
+                "Deaths on the map (blue base bottom left)",
+                Some(theme.accent),
+                None,
+            ));
+            body.extend(self.death_map());
+        }
+
+        body
+    }
+}
+
+/// Formats a time of the game, in milliseconds, e.g. `07:45`.
+fn game_time(milliseconds: i64) -> String {
+    let seconds = milliseconds / 1000;
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
@@ -1,13 +1,19 @@
 use crate::data::{
-    champion_stats::RecentChampionInfo, mastery::Mastery, match_history::MatchHistory,
-    summoner::Summoner, ApplicationData,
+    champion_pool::ChampionPool, champion_stats::RecentChampionInfo, comparison::Comparison,
+    live_client::LiveStats, live_game::LiveGame, lp_history::LpHistory, mastery::Mastery,
+    match_history::MatchHistory, matchups::Matchups, recent_form::RecentForm,
+    refresh::RefreshSummary, role_stats::RoleStats, scoreboard::Scoreboard, summoner::Summoner,
+    team::Team, teammates::FrequentTeammates, timeline::TimelineAnalysis,
 };
 use anyhow::Result;
 use enum_dispatch::enum_dispatch;
 use lolfetch_color::ColoredString;
-use std::io::Write;
-use termcolor::{Buffer, BufferWriter, ColorChoice};
+use termcolor::Buffer;
 
+pub mod export;
+pub mod layout;
+pub mod template;
+pub mod theme;
 pub mod utils;
 
 pub const CENTER_PAD_LENGTH: usize = 5;
@@ -31,6 +37,19 @@ pub enum DisplayableSectionKind {
     MatchHistory,
     RecentChampionInfo,
     Mastery,
+    RoleStats,
+    RecentForm,
+    LpHistory,
+    Matchups,
+    FrequentTeammates,
+    ChampionPool,
+    Comparison,
+    Team,
+    LiveGame,
+    LiveStats,
+    RefreshSummary,
+    Scoreboard,
+    TimelineAnalysis,
 }
 
 impl DisplayableSectionKind {
@@ -38,10 +57,11 @@ impl DisplayableSectionKind {
         let mut vec = Vec::new();
 
         if let Some(header) = self.header() {
-            vec.push(ColoredString::from_str(&header, None, None));
+            let theme = theme::current();
+            vec.push(ColoredString::from_str(&header, theme.header, None));
             vec.push(ColoredString::from_str(
                 "-".repeat(CENTER_PAD_LENGTH).as_str(),
-                None,
+                theme.separator,
                 None,
             ));
         }
@@ -66,67 +86,3 @@ impl ColoredStringDisplayer for ColoredString {
         Ok(())
     }
 }
-
-pub struct Layout {
-    processed: ApplicationData,
-}
-
-impl Layout {
-    pub const fn new(processed: ApplicationData) -> Self {
-        Self { processed }
-    }
-
-    pub fn display(&self) -> Result<()> {
-        let mut logo_lines = self.processed.image.iter();
-        let mut info_lines = Vec::new();
-
-        for (i, section) in self.processed.sections.iter().enumerate() {
-            if i != 0 {
-                info_lines.push(ColoredString::from_str("", None, None));
-            }
-            info_lines.extend(section.to_colored_string_vec());
-        }
-
-        let mut info_lines = info_lines.iter();
-
-        let buffer = BufferWriter::stdout(ColorChoice::Always);
-
-        if logo_lines.len() > info_lines.len() {
-            let diff = (logo_lines.len() - info_lines.len()) / 2;
-            for (i, logo_line) in logo_lines.enumerate() {
-                if i < diff {
-                    buffer.print(&Self::format_line(Some(logo_line), None)?)?;
-                } else {
-                    buffer.print(&Self::format_line(Some(logo_line), info_lines.next())?)?;
-                }
-            }
-        } else {
-            for info_line in info_lines {
-                buffer.print(&Self::format_line(logo_lines.next(), Some(info_line))?)?;
-            }
-        }
-
-        Ok(())
-    }
-
-    fn format_line(
-        logo_line: Option<&ColoredString>,
-        info_line: Option<&ColoredString>,
-    ) -> Result<Buffer> {
-        let mut buffer = BufferWriter::stdout(ColorChoice::Always).buffer();
-
-        match logo_line {
-            Some(logo) => logo.display(&mut buffer)?,
-            None => buffer.write_all(" ".repeat(IMAGE_WIDTH as usize).as_bytes())?,
-        }
-
-        if let Some(info) = info_line {
-            buffer.write_all(" ".repeat(CENTER_PAD_LENGTH).as_bytes())?;
-            info.display(&mut buffer)?;
-        }
-
-        buffer.write_all(b"\n")?;
-
-        Ok(buffer)
-    }
-}
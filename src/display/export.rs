@@ -0,0 +1,218 @@
+//! Exports the composed output to files, so it can be shared outside of a terminal.
+
+use clap::ValueEnum;
+use lolfetch_color::{ColoredChar, ColoredString};
+use std::{fs, path::Path};
+use termcolor::Color;
+use thiserror::Error;
+
+mod html;
+mod png;
+mod svg;
+
+/// Foreground color used for uncolored characters.
+const DEFAULT_FOREGROUND: Rgb = Rgb(208, 208, 208);
+
+/// Background color of the exported card.
+const DEFAULT_BACKGROUND: Rgb = Rgb(24, 24, 27);
+
+#[derive(Error, Debug)]
+pub enum ExportError {
+    #[error("Could not guess the export format of {0}, use --export-format")]
+    UnknownFormat(String),
+
+    #[error("Failed to write export file: {0}")]
+    Write(#[from] std::io::Error),
+
+    #[error("Failed to encode image: {0}")]
+    Image(#[from] image::ImageError),
+}
+
+/// File formats the output can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Standalone HTML page
+    Html,
+
+    /// SVG image with monospace text
+    Svg,
+
+    /// Rasterized PNG image
+    Png,
+}
+
+impl ExportFormat {
+    /// Guesses the format from the extension of the file.
+    pub fn from_path(path: &Path) -> Result<Self, ExportError> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+
+        match extension.as_deref() {
+            Some("html" | "htm") => Ok(Self::Html),
+            Some("svg") => Ok(Self::Svg),
+            Some("png") => Ok(Self::Png),
+            _ => Err(ExportError::UnknownFormat(path.display().to_string())),
+        }
+    }
+}
+
+/// Writes the lines to `path` in the given format.
+pub fn export(
+    lines: &[ColoredString],
+    format: ExportFormat,
+    path: &Path,
+) -> Result<(), ExportError> {
+    info!("Exporting output to {}", path.display());
+
+    match format {
+        ExportFormat::Html => fs::write(path, html::render(lines))?,
+        ExportFormat::Svg => fs::write(path, svg::render(lines))?,
+        ExportFormat::Png => png::render(lines).save_with_format(path, image::ImageFormat::Png)?,
+    }
+
+    Ok(())
+}
+
+/// RGB color, as used in the exported files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rgb(u8, u8, u8);
+
+impl Rgb {
+    /// Converts a terminal color to RGB, using the xterm palette.
+    fn from_color(color: Color) -> Self {
+        match color {
+            Color::Black => Self(0, 0, 0),
+            Color::Red => Self(205, 49, 49),
+            Color::Green => Self(13, 188, 121),
+            Color::Yellow => Self(229, 229, 16),
+            Color::Blue => Self(36, 114, 200),
+            Color::Magenta => Self(188, 63, 188),
+            Color::Cyan => Self(17, 168, 205),
+            Color::White => Self(229, 229, 229),
+            Color::Ansi256(index) => Self::from_ansi256(index),
+            Color::Rgb(r, g, b) => Self(r, g, b),
+            // `termcolor::Color` is non exhaustive
+            _ => DEFAULT_FOREGROUND,
+        }
+    }
+
+    fn from_ansi256(index: u8) -> Self {
+        const SYSTEM: [Rgb; 16] = [
+            Rgb(0, 0, 0),
+            Rgb(128, 0, 0),
+            Rgb(0, 128, 0),
+            Rgb(128, 128, 0),
+            Rgb(0, 0, 128),
+            Rgb(128, 0, 128),
+            Rgb(0, 128, 128),
+            Rgb(192, 192, 192),
+            Rgb(128, 128, 128),
+            Rgb(255, 0, 0),
+            Rgb(0, 255, 0),
+            Rgb(255, 255, 0),
+            Rgb(0, 0, 255),
+            Rgb(255, 0, 255),
+            Rgb(0, 255, 255),
+            Rgb(255, 255, 255),
+        ];
+        const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+        match index {
+            0..=15 => SYSTEM[index as usize],
+            16..=231 => {
+                let index = index - 16;
+                Self(
+                    CUBE_LEVELS[(index / 36) as usize],
+                    CUBE_LEVELS[(index / 6 % 6) as usize],
+                    CUBE_LEVELS[(index % 6) as usize],
+                )
+            }
+            232..=255 => {
+                let level = 8 + (index - 232) * 10;
+                Self(level, level, level)
+            }
+        }
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Sequence of characters sharing the same colors.
+struct Run {
+    /// Column of the first character.
+    start: usize,
+    text: String,
+    foreground: Rgb,
+    background: Option<Rgb>,
+}
+
+/// Groups the characters of a line by colors.
+fn runs(line: &ColoredString) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+
+    for (column, character) in line.iter().enumerate() {
+        let (foreground, background) = colors(character);
+        match runs.last_mut() {
+            Some(run) if run.foreground == foreground && run.background == background => {
+                run.text.push(character.character());
+            }
+            _ => runs.push(Run {
+                start: column,
+                text: character.character().to_string(),
+                foreground,
+                background,
+            }),
+        }
+    }
+
+    runs
+}
+
+fn colors(character: &ColoredChar) -> (Rgb, Option<Rgb>) {
+    (
+        character
+            .color()
+            .map_or(DEFAULT_FOREGROUND, Rgb::from_color),
+        character.background().map(Rgb::from_color),
+    )
+}
+
+/// Escapes the characters that have a meaning in HTML and XML.
+fn escape_xml(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&#39;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(16 => Rgb(0, 0, 0) ; "cube start")]
+    #[test_case(208 => Rgb(255, 135, 0) ; "orange")]
+    #[test_case(231 => Rgb(255, 255, 255) ; "cube end")]
+    #[test_case(244 => Rgb(128, 128, 128) ; "grayscale")]
+    fn test_ansi256_to_rgb(index: u8) -> Rgb {
+        Rgb::from_ansi256(index)
+    }
+
+    #[test_case("card.HTML" => Some(ExportFormat::Html) ; "html")]
+    #[test_case("card.svg" => Some(ExportFormat::Svg) ; "svg")]
+    #[test_case("out/card.png" => Some(ExportFormat::Png) ; "png")]
+    #[test_case("card" => None ; "no extension")]
+    fn test_format_from_path(path: &str) -> Option<ExportFormat> {
+        ExportFormat::from_path(Path::new(path)).ok()
+    }
+}
@@ -0,0 +1,44 @@
+//! HTML export, as a standalone page.
+
+use super::{escape_xml, runs, DEFAULT_BACKGROUND, DEFAULT_FOREGROUND};
+use lolfetch_color::ColoredString;
+use std::fmt::Write;
+
+pub fn render(lines: &[ColoredString]) -> String {
+    let mut body = String::new();
+
+    for line in lines {
+        for run in runs(line) {
+            let mut style = format!("color:{}", run.foreground.hex());
+            if let Some(background) = run.background {
+                let _ = write!(style, ";background:{}", background.hex());
+            }
+            let _ = write!(
+                body,
+                "<span style=\"{style}\">{}</span>",
+                escape_xml(&run.text)
+            );
+        }
+        body.push('\n');
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>lolfetch</title>
+<style>
+body {{ background: {background}; margin: 0; padding: 24px; }}
+pre {{ color: {foreground}; font-family: "DejaVu Sans Mono", Menlo, Consolas, monospace; font-size: 14px; line-height: 1.2; margin: 0; }}
+</style>
+</head>
+<body>
+<pre>{body}</pre>
+</body>
+</html>
+"#,
+        background = DEFAULT_BACKGROUND.hex(),
+        foreground = DEFAULT_FOREGROUND.hex(),
+    )
+}
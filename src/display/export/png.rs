@@ -0,0 +1,201 @@
+//! PNG export, rasterized with a bitmap font.
+
+use super::{colors, Rgb, DEFAULT_BACKGROUND};
+use embedded_graphics::{
+    mono_font::{iso_8859_1::FONT_8X13, MonoTextStyle},
+    pixelcolor::Rgb888,
+    prelude::*,
+    text::{Baseline, Text},
+};
+use image::{imageops, RgbImage};
+use lolfetch_color::ColoredString;
+use std::convert::Infallible;
+
+const CELL_WIDTH: u32 = 8;
+const CELL_HEIGHT: u32 = 13;
+const MARGIN: u32 = 12;
+/// The bitmap font is small, the image is upscaled to be readable once shared.
+const SCALE: u32 = 2;
+
+pub fn render(lines: &[ColoredString]) -> RgbImage {
+    let columns = lines.iter().map(ColoredString::len).max().unwrap_or(0) as u32;
+    let width = columns * CELL_WIDTH + 2 * MARGIN;
+    let height = lines.len() as u32 * CELL_HEIGHT + 2 * MARGIN;
+
+    let mut canvas = Canvas(RgbImage::from_pixel(
+        width,
+        height,
+        pixel(DEFAULT_BACKGROUND),
+    ));
+
+    for (row, line) in lines.iter().enumerate() {
+        for (column, character) in line.iter().enumerate() {
+            let x = MARGIN + column as u32 * CELL_WIDTH;
+            let y = MARGIN + row as u32 * CELL_HEIGHT;
+            let (foreground, background) = colors(character);
+
+            if let Some(background) = background {
+                canvas.fill(x, y, CELL_WIDTH, CELL_HEIGHT, background);
+            }
+            canvas.draw_char(character.character(), x, y, foreground);
+        }
+    }
+
+    imageops::resize(
+        &canvas.0,
+        width * SCALE,
+        height * SCALE,
+        imageops::FilterType::Nearest,
+    )
+}
+
+fn pixel(Rgb(r, g, b): Rgb) -> image::Rgb<u8> {
+    image::Rgb([r, g, b])
+}
+
+/// Image the text is drawn on.
+struct Canvas(RgbImage);
+
+impl Canvas {
+    fn fill(&mut self, x: u32, y: u32, width: u32, height: u32, color: Rgb) {
+        for py in y..(y + height).min(self.0.height()) {
+            for px in x..(x + width).min(self.0.width()) {
+                self.0.put_pixel(px, py, pixel(color));
+            }
+        }
+    }
+
+    /// Draws a character in the cell at (`x`, `y`).
+    /// Block elements, shades, box corners and lines, and ellipses are not part of the font
+    /// and are drawn by hand.
+    fn draw_char(&mut self, character: char, x: u32, y: u32, color: Rgb) {
+        let (mid_x, mid_y) = (CELL_WIDTH / 2, CELL_HEIGHT / 2);
+
+        match character {
+            ' ' => {}
+            // Full and left partial blocks, from 8/8 to 1/8
+            '█'..='▏' => {
+                let eighths = '▏' as u32 - character as u32 + 1;
+                self.fill(x, y, CELL_WIDTH * eighths / 8, CELL_HEIGHT, color);
+            }
+            // Lower partial blocks, from 1/8 to 7/8
+            '▁'..='▇' => {
+                let eighths = character as u32 - '▁' as u32 + 1;
+                let block_height = CELL_HEIGHT * eighths / 8;
+                self.fill(
+                    x,
+                    y + CELL_HEIGHT - block_height,
+                    CELL_WIDTH,
+                    block_height,
+                    color,
+                );
+            }
+            '▀' => self.fill(x, y, CELL_WIDTH, CELL_HEIGHT / 2, color),
+            // Light, medium and dark shades, filling 1/4, 1/2 and 3/4 of the pixels
+            '░' | '▒' | '▓' => {
+                for py in 0..CELL_HEIGHT {
+                    for px in 0..CELL_WIDTH {
+                        let lit = match character {
+                            '░' => px % 2 == 0 && py % 2 == 0,
+                            '▒' => (px + py) % 2 == 0,
+                            _ => px % 2 == 0 || py % 2 == 0,
+                        };
+                        if lit {
+                            self.fill(x + px, y + py, 1, 1, color);
+                        }
+                    }
+                }
+            }
+            '─' => self.fill(x, y + mid_y, CELL_WIDTH, 1, color),
+            '│' => self.fill(x + mid_x, y, 1, CELL_HEIGHT, color),
+            '┌' | '┐' | '└' | '┘' => {
+                // Half line from the center towards the right or the left
+                let (line_x, line_width) = match character {
+                    '┌' | '└' => (x + mid_x, CELL_WIDTH - mid_x),
+                    _ => (x, mid_x + 1),
+                };
+                self.fill(line_x, y + mid_y, line_width, 1, color);
+                // Half line from the center towards the bottom or the top
+                let (line_y, line_height) = match character {
+                    '┌' | '┐' => (y + mid_y, CELL_HEIGHT - mid_y),
+                    _ => (y, mid_y + 1),
+                };
+                self.fill(x + mid_x, line_y, 1, line_height, color);
+            }
+            '…' => {
+                for dot in 0..3 {
+                    self.fill(x + 1 + dot * 2, y + CELL_HEIGHT - 3, 1, 1, color);
+                }
+            }
+            character => {
+                let style = MonoTextStyle::new(&FONT_8X13, Rgb888::new(color.0, color.1, color.2));
+                let mut buffer = [0; 4];
+                let _ = Text::with_baseline(
+                    character.encode_utf8(&mut buffer),
+                    Point::new(x as i32, y as i32),
+                    style,
+                    Baseline::Top,
+                )
+                .draw(self);
+            }
+        }
+    }
+}
+
+impl OriginDimensions for Canvas {
+    fn size(&self) -> Size {
+        Size::new(self.0.width(), self.0.height())
+    }
+}
+
+impl DrawTarget for Canvas {
+    type Color = Rgb888;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let (Ok(x), Ok(y)) = (u32::try_from(point.x), u32::try_from(point.y)) {
+                if x < self.0.width() && y < self.0.height() {
+                    self.0
+                        .put_pixel(x, y, image::Rgb([color.r(), color.g(), color.b()]));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const COLOR: Rgb = Rgb(255, 255, 255);
+
+    /// Returns the number of pixels of a cell lit by the character.
+    fn lit_pixels(character: char) -> usize {
+        let mut canvas = Canvas(RgbImage::from_pixel(
+            CELL_WIDTH,
+            CELL_HEIGHT,
+            pixel(DEFAULT_BACKGROUND),
+        ));
+        canvas.draw_char(character, 0, 0, COLOR);
+        canvas.0.pixels().filter(|&&p| p == pixel(COLOR)).count()
+    }
+
+    #[test_case('─' => 8 ; "horizontal line")]
+    #[test_case('│' => 13 ; "vertical line")]
+    #[test_case('┌' => 10 ; "top left corner")]
+    #[test_case('┘' => 11 ; "bottom right corner")]
+    #[test_case('░' => 28 ; "light shade")]
+    #[test_case('▒' => 52 ; "medium shade")]
+    #[test_case('▓' => 80 ; "dark shade")]
+    #[test_case('▀' => 48 ; "upper half block")]
+    #[test_case('█' => 104 ; "full block")]
+    fn test_draw_char(character: char) -> usize {
+        lit_pixels(character)
+    }
+}
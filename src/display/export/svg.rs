@@ -0,0 +1,62 @@
+//! SVG export, with one text element per line.
+
+use super::{escape_xml, runs, DEFAULT_BACKGROUND};
+use lolfetch_color::ColoredString;
+use std::fmt::Write;
+
+const FONT_SIZE: f64 = 14.0;
+const CELL_WIDTH: f64 = FONT_SIZE * 0.6;
+const CELL_HEIGHT: f64 = FONT_SIZE * 1.2;
+const MARGIN: f64 = 24.0;
+
+pub fn render(lines: &[ColoredString]) -> String {
+    let columns = lines.iter().map(ColoredString::len).max().unwrap_or(0);
+    let width = columns as f64 * CELL_WIDTH + 2.0 * MARGIN;
+    let height = lines.len() as f64 * CELL_HEIGHT + 2.0 * MARGIN;
+
+    let mut backgrounds = String::new();
+    let mut texts = String::new();
+
+    for (row, line) in lines.iter().enumerate() {
+        let top = MARGIN + row as f64 * CELL_HEIGHT;
+        let baseline = top + FONT_SIZE;
+
+        let mut spans = String::new();
+        for run in runs(line) {
+            let x = MARGIN + run.start as f64 * CELL_WIDTH;
+            let length = run.text.chars().count() as f64 * CELL_WIDTH;
+
+            if let Some(background) = run.background {
+                let _ = writeln!(
+                    backgrounds,
+                    r#"<rect x="{x:.1}" y="{top:.1}" width="{length:.1}" height="{CELL_HEIGHT:.1}" fill="{}"/>"#,
+                    background.hex()
+                );
+            }
+            if !run.text.trim().is_empty() {
+                let _ = write!(
+                    spans,
+                    r#"<tspan x="{x:.1}" fill="{}">{}</tspan>"#,
+                    run.foreground.hex(),
+                    escape_xml(&run.text)
+                );
+            }
+        }
+
+        if !spans.is_empty() {
+            let _ = writeln!(texts, r#"<text y="{baseline:.1}">{spans}</text>"#);
+        }
+    }
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{height:.0}" viewBox="0 0 {width:.1} {height:.1}">
+<rect width="100%" height="100%" fill="{background}"/>
+<g shape-rendering="crispEdges">
+{backgrounds}</g>
+<g font-family="DejaVu Sans Mono, Menlo, Consolas, monospace" font-size="{FONT_SIZE}" xml:space="preserve">
+{texts}</g>
+</svg>
+"#,
+        background = DEFAULT_BACKGROUND.hex(),
+    )
+}
@@ -0,0 +1,261 @@
+//! Layout engine, responsible for placing the image and the information sections
+//! next to (or above) each other depending on the available terminal width.
+
+use super::{
+    export::{self, ExportFormat},
+    ColoredStringDisplayer, CENTER_PAD_LENGTH, IMAGE_WIDTH,
+};
+use crate::{
+    cli::lolfetch::{ImagePosition, LayoutKind, VerticalAlign},
+    data::ApplicationData,
+};
+use anyhow::Result;
+use lolfetch_color::ColoredString;
+use std::{io::Write, path::Path};
+use termcolor::{BufferWriter, ColorChoice};
+
+/// Minimum width kept for the information column before giving up on the side-by-side layout.
+const MIN_SIDE_INFO_WIDTH: usize = 40;
+
+/// Options used to compose the output.
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutOptions {
+    /// Requested layout, `Auto` picks one based on the terminal width.
+    pub kind: LayoutKind,
+
+    /// Side of the image in the side-by-side layout.
+    pub image_position: ImagePosition,
+
+    /// Vertical alignment of the shortest column in the side-by-side layout.
+    pub vertical_align: VerticalAlign,
+
+    /// Forced width of the output, the terminal width is measured if not set.
+    pub width: Option<usize>,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        Self {
+            kind: LayoutKind::Auto,
+            image_position: ImagePosition::Left,
+            vertical_align: VerticalAlign::Center,
+            width: None,
+        }
+    }
+}
+
+/// Arrangement chosen for the output, after taking the available width into account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Arrangement {
+    SideBySide,
+    Stacked,
+    InfoOnly,
+}
+
+impl Arrangement {
+    /// Chooses the arrangement from the requested layout and the available width.
+    fn choose(
+        kind: LayoutKind,
+        width: Option<usize>,
+        image_width: usize,
+        info_width: usize,
+    ) -> Self {
+        match kind {
+            LayoutKind::SideBySide => Self::SideBySide,
+            LayoutKind::Stacked => Self::Stacked,
+            LayoutKind::InfoOnly => Self::InfoOnly,
+            LayoutKind::Auto => {
+                let Some(width) = width else {
+                    return Self::SideBySide;
+                };
+                let info_room = width.saturating_sub(image_width + CENTER_PAD_LENGTH);
+                if info_room >= info_width.min(MIN_SIDE_INFO_WIDTH) {
+                    Self::SideBySide
+                } else if width >= image_width {
+                    Self::Stacked
+                } else {
+                    Self::InfoOnly
+                }
+            }
+        }
+    }
+}
+
+pub struct Layout {
+    processed: ApplicationData,
+    options: LayoutOptions,
+}
+
+impl Layout {
+    pub const fn new(processed: ApplicationData, options: LayoutOptions) -> Self {
+        Self { processed, options }
+    }
+
+    pub fn display(&self) -> Result<()> {
+        let writer = BufferWriter::stdout(ColorChoice::Always);
+        let width = self.options.width.or_else(terminal_width);
+
+        for line in self.compose(width) {
+            let mut buffer = writer.buffer();
+            line.display(&mut buffer)?;
+            buffer.write_all(b"\n")?;
+            writer.print(&buffer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Exports the output to a file, using the forced width if any.
+    pub fn export(&self, path: &Path, format: ExportFormat) -> Result<()> {
+        export::export(&self.compose(self.options.width), format, path)?;
+        Ok(())
+    }
+
+    /// Composes the image and the sections into the lines to print,
+    /// none of them being wider than `width` (if given).
+    pub fn compose(&self, width: Option<usize>) -> Vec<ColoredString> {
+        let info_lines = self.info_lines();
+        let image = &self.processed.image;
+
+        let image_width = image
+            .iter()
+            .map(ColoredString::len)
+            .max()
+            .unwrap_or(IMAGE_WIDTH as usize);
+        let info_width = info_lines.iter().map(ColoredString::len).max().unwrap_or(0);
+
+        match Arrangement::choose(self.options.kind, width, image_width, info_width) {
+            Arrangement::SideBySide => {
+                let info_limit =
+                    width.map(|width| width.saturating_sub(image_width + CENTER_PAD_LENGTH));
+                self.side_by_side(image, image_width, info_lines, info_limit)
+            }
+            Arrangement::Stacked => {
+                let mut lines = image.clone();
+                lines.push(ColoredString::new());
+                lines.extend(info_lines);
+                truncate_all(lines, width)
+            }
+            Arrangement::InfoOnly => truncate_all(info_lines, width),
+        }
+    }
+
+    /// Returns the lines of every section, separated by an empty line.
+    fn info_lines(&self) -> Vec<ColoredString> {
+        let mut info_lines = Vec::new();
+
+        for (i, section) in self.processed.sections.iter().enumerate() {
+            if i != 0 {
+                info_lines.push(ColoredString::new());
+            }
+            info_lines.extend(section.to_colored_string_vec());
+        }
+
+        info_lines
+    }
+
+    fn side_by_side(
+        &self,
+        image: &[ColoredString],
+        image_width: usize,
+        info_lines: Vec<ColoredString>,
+        info_limit: Option<usize>,
+    ) -> Vec<ColoredString> {
+        let info_lines = truncate_all(info_lines, info_limit);
+        let info_width = info_lines.iter().map(ColoredString::len).max().unwrap_or(0);
+
+        let height = image.len().max(info_lines.len());
+        let align = self.options.vertical_align;
+        let image_offset = align.offset(image.len(), height);
+        let info_offset = align.offset(info_lines.len(), height);
+
+        let pad = ColoredString::from_unformatted_str(&" ".repeat(CENTER_PAD_LENGTH));
+
+        (0..height)
+            .map(|row| {
+                let mut image_line = row
+                    .checked_sub(image_offset)
+                    .and_then(|i| image.get(i))
+                    .cloned()
+                    .unwrap_or_default();
+                image_line.pad_to(image_width);
+
+                let info_line = row.checked_sub(info_offset).and_then(|i| info_lines.get(i));
+
+                let mut line = ColoredString::new();
+                match self.options.image_position {
+                    ImagePosition::Left => {
+                        line.join(&image_line);
+                        if let Some(info) = info_line {
+                            line.join(&pad);
+                            line.join(info);
+                        }
+                    }
+                    ImagePosition::Right => {
+                        let mut info = info_line.cloned().unwrap_or_default();
+                        info.pad_to(info_width);
+                        line.join(&info);
+                        line.join(&pad);
+                        line.join(&image_line);
+                    }
+                }
+                line
+            })
+            .collect()
+    }
+}
+
+impl VerticalAlign {
+    /// Returns the first row of a block of `len` rows in a column of `height` rows.
+    const fn offset(self, len: usize, height: usize) -> usize {
+        let free = height.saturating_sub(len);
+        match self {
+            Self::Top => 0,
+            Self::Center => free / 2,
+            Self::Bottom => free,
+        }
+    }
+}
+
+fn truncate_all(mut lines: Vec<ColoredString>, width: Option<usize>) -> Vec<ColoredString> {
+    if let Some(width) = width {
+        lines.iter_mut().for_each(|line| line.truncate(width));
+    }
+    lines
+}
+
+/// Returns the width of the terminal, if it can be measured.
+fn terminal_width() -> Option<usize> {
+    match crossterm::terminal::size() {
+        Ok((columns, _)) if columns > 0 => Some(columns as usize),
+        _ => std::env::var("COLUMNS").ok()?.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(LayoutKind::Auto, None, 120 => Arrangement::SideBySide ; "unknown width")]
+    #[test_case(LayoutKind::Auto, Some(200), 120 => Arrangement::SideBySide ; "wide terminal")]
+    #[test_case(LayoutKind::Auto, Some(100), 120 => Arrangement::SideBySide ; "truncated info")]
+    #[test_case(LayoutKind::Auto, Some(80), 120 => Arrangement::Stacked ; "narrow terminal")]
+    #[test_case(LayoutKind::Auto, Some(30), 120 => Arrangement::InfoOnly ; "tiny terminal")]
+    #[test_case(LayoutKind::Stacked, Some(200), 120 => Arrangement::Stacked ; "forced stacked")]
+    fn test_choose_arrangement(
+        kind: LayoutKind,
+        width: Option<usize>,
+        info_width: usize,
+    ) -> Arrangement {
+        Arrangement::choose(kind, width, IMAGE_WIDTH as usize, info_width)
+    }
+
+    #[test_case(VerticalAlign::Top, 5, 11 => 0 ; "top")]
+    #[test_case(VerticalAlign::Center, 5, 11 => 3 ; "center")]
+    #[test_case(VerticalAlign::Bottom, 5, 11 => 6 ; "bottom")]
+    #[test_case(VerticalAlign::Center, 11, 5 => 0 ; "taller than column")]
+    fn test_vertical_offset(align: VerticalAlign, len: usize, height: usize) -> usize {
+        align.offset(len, height)
+    }
+}
@@ -0,0 +1,492 @@
+//! Small template language used to format the rows of the sections.
+//!
+//! A template is made of literal text and placeholders:
+//! - `{field}` inserts the value of a field of the section.
+//! - `{field@index}` inserts a value of an indexed field (e.g. `{gd@15}`, the gold difference at 15 minutes).
+//! - `{field:spec}` formats the value, where `spec` is made of (in this order, all optional):
+//!   - an alignment: `<` (left), `>` (right) or `^` (center), padding the value to the widest
+//!     value of the column,
+//!   - a minimum width (e.g. `8`),
+//!   - `+`, which prefixes positive numbers with a sign and colors the value
+//!     with the positive / negative colors of the theme,
+//!   - `|color`, which forces the color of the value (see [`theme::parse_color`]).
+//! - `[...]` is an optional group, only rendered if every field inside of it has a value.
+//!
+//! `{{`, `}}`, `[[` and `]]` are used to write the corresponding literal characters.
+
+use super::theme::{self, ThemeError};
+use lolfetch_color::ColoredString;
+use std::collections::{BTreeMap, HashMap};
+use termcolor::Color;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TemplateError {
+    #[error("Unclosed placeholder in template")]
+    UnclosedPlaceholder,
+
+    #[error("Unclosed optional group in template")]
+    UnclosedGroup,
+
+    #[error("Unexpected {0:?} in template, use {0}{0} to write it")]
+    UnexpectedCharacter(char),
+
+    #[error("Invalid format {0:?} in template")]
+    InvalidSpec(String),
+
+    #[error("Unknown field {field:?} in template (available fields: {available})")]
+    UnknownField { field: String, available: String },
+
+    #[error("{0}")]
+    InvalidColor(#[from] ThemeError),
+}
+
+/// Value of a field, as provided by a section.
+#[derive(Debug, Clone)]
+pub struct Field {
+    text: String,
+    color: Option<Color>,
+    number: Option<f64>,
+    widget: Option<ColoredString>,
+    entries: Option<BTreeMap<u32, Field>>,
+}
+
+impl Field {
+    /// Plain text value.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            color: None,
+            number: None,
+            widget: None,
+            entries: None,
+        }
+    }
+
+    /// Already rendered value (e.g. a bar), inserted as is.
+    pub fn widget(widget: ColoredString) -> Self {
+        Self {
+            widget: Some(widget),
+            ..Self::text("")
+        }
+    }
+
+    /// Text value with its own color.
+    pub fn colored(text: impl Into<String>, color: Option<Color>) -> Self {
+        Self {
+            color,
+            ..Self::text(text)
+        }
+    }
+
+    /// Values indexed by a number, only reachable with `{field@index}`.
+    pub fn indexed(entries: impl IntoIterator<Item = (u32, Self)>) -> Self {
+        Self {
+            entries: Some(entries.into_iter().collect()),
+            ..Self::text("")
+        }
+    }
+
+    /// Numeric value, `text` being its formatted representation.
+    pub fn number(value: f64, text: impl Into<String>) -> Self {
+        Self {
+            number: Some(value),
+            ..Self::text(text)
+        }
+    }
+}
+
+/// Values of the fields of a row, missing fields are rendered empty.
+pub type Fields = HashMap<&'static str, Field>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+#[derive(Debug, Clone)]
+struct Placeholder {
+    /// Index of the placeholder in the template, used to align the columns.
+    id: usize,
+    name: String,
+    index: Option<u32>,
+    align: Option<Align>,
+    width: usize,
+    signed: bool,
+    color: Option<Color>,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Literal(String),
+    Field(Placeholder),
+    Optional(Vec<Segment>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Template {
+    segments: Vec<Segment>,
+    placeholders: usize,
+}
+
+impl Template {
+    pub fn parse(template: &str) -> Result<Self, TemplateError> {
+        let mut chars = template.chars().peekable();
+        let mut placeholders = 0;
+        let mut stack: Vec<Vec<Segment>> = vec![Vec::new()];
+        let mut literal = String::new();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' | '}' | '[' | ']' if chars.peek() == Some(&c) => {
+                    chars.next();
+                    literal.push(c);
+                }
+                '{' => {
+                    let mut content = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => content.push(c),
+                            None => return Err(TemplateError::UnclosedPlaceholder),
+                        }
+                    }
+                    let segments = stack.last_mut().expect("The stack is never empty");
+                    flush_literal(&mut literal, segments);
+                    segments.push(Segment::Field(Placeholder::parse(&content, placeholders)?));
+                    placeholders += 1;
+                }
+                '[' => {
+                    flush_literal(
+                        &mut literal,
+                        stack.last_mut().expect("The stack is never empty"),
+                    );
+                    stack.push(Vec::new());
+                }
+                ']' if stack.len() > 1 => {
+                    let mut group = stack.pop().expect("The stack has a group");
+                    flush_literal(&mut literal, &mut group);
+                    stack
+                        .last_mut()
+                        .expect("The stack is never empty")
+                        .push(Segment::Optional(group));
+                }
+                '}' | ']' => return Err(TemplateError::UnexpectedCharacter(c)),
+                c => literal.push(c),
+            }
+        }
+
+        if stack.len() > 1 {
+            return Err(TemplateError::UnclosedGroup);
+        }
+        let mut segments = stack.pop().expect("The stack is never empty");
+        flush_literal(&mut literal, &mut segments);
+
+        Ok(Self {
+            segments,
+            placeholders,
+        })
+    }
+
+    /// Checks that every field of the template is part of `available`.
+    pub fn validate(&self, available: &[&str]) -> Result<(), TemplateError> {
+        fn check(segments: &[Segment], available: &[&str]) -> Result<(), TemplateError> {
+            segments.iter().try_for_each(|segment| match segment {
+                Segment::Literal(_) => Ok(()),
+                Segment::Field(placeholder) if placeholder.is_available(available) => Ok(()),
+                Segment::Field(placeholder) => Err(TemplateError::UnknownField {
+                    field: placeholder.full_name(),
+                    available: available.join(", "),
+                }),
+                Segment::Optional(group) => check(group, available),
+            })
+        }
+
+        check(&self.segments, available)
+    }
+
+    /// Renders every row, aligning the fields that request it across rows.
+    pub fn render(&self, rows: &[Fields]) -> Vec<ColoredString> {
+        let mut widths = vec![0; self.placeholders];
+        for row in rows {
+            self.measure(&self.segments, row, &mut widths);
+        }
+
+        rows.iter()
+            .map(|row| {
+                let mut line = ColoredString::new();
+                self.render_segments(&self.segments, row, &widths, &mut line);
+                line
+            })
+            .collect()
+    }
+
+    fn measure(&self, segments: &[Segment], row: &Fields, widths: &mut [usize]) {
+        for segment in segments {
+            match segment {
+                Segment::Literal(_) => {}
+                Segment::Field(placeholder) => {
+                    if let Some(field) = placeholder.lookup(row) {
+                        let width = placeholder.format(field).len();
+                        widths[placeholder.id] = widths[placeholder.id].max(width);
+                    }
+                }
+                Segment::Optional(group) => self.measure(group, row, widths),
+            }
+        }
+    }
+
+    fn render_segments(
+        &self,
+        segments: &[Segment],
+        row: &Fields,
+        widths: &[usize],
+        line: &mut ColoredString,
+    ) {
+        for segment in segments {
+            match segment {
+                Segment::Literal(text) => line.push_unformatted_str(text),
+                Segment::Field(placeholder) => {
+                    let value = placeholder
+                        .lookup(row)
+                        .map(|field| placeholder.format(field))
+                        .unwrap_or_default();
+                    let width = if placeholder.align.is_some() {
+                        widths[placeholder.id].max(placeholder.width)
+                    } else {
+                        placeholder.width
+                    };
+                    line.join(&pad(value, width, placeholder.align));
+                }
+                Segment::Optional(group) => {
+                    if has_all_fields(group, row) {
+                        self.render_segments(group, row, widths, line);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Placeholder {
+    fn parse(content: &str, id: usize) -> Result<Self, TemplateError> {
+        let (name, spec) = content.split_once(':').unwrap_or((content, ""));
+        let (spec, color) = match spec.split_once('|') {
+            Some((spec, color)) => (spec, Some(theme::parse_color(color)?)),
+            None => (spec, None),
+        };
+        let invalid = || TemplateError::InvalidSpec(content.to_string());
+
+        let mut rest = spec;
+        let align = match rest.chars().next() {
+            Some('<') => Some(Align::Left),
+            Some('>') => Some(Align::Right),
+            Some('^') => Some(Align::Center),
+            _ => None,
+        };
+        if align.is_some() {
+            rest = &rest[1..];
+        }
+
+        let signed = rest.ends_with('+');
+        if signed {
+            rest = &rest[..rest.len() - 1];
+        }
+
+        let width = if rest.is_empty() {
+            0
+        } else {
+            rest.parse().map_err(|_| invalid())?
+        };
+
+        let (name, index) = match name.trim().split_once('@') {
+            Some((name, index)) => (name, Some(index.parse().map_err(|_| invalid())?)),
+            None => (name.trim(), None),
+        };
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            id,
+            name: name.to_string(),
+            index,
+            align,
+            width,
+            signed,
+            color,
+        })
+    }
+
+    /// Indexed fields are listed as `name@<index>` in the available fields.
+    fn is_available(&self, available: &[&str]) -> bool {
+        available
+            .iter()
+            .any(|field| match (field.split_once('@'), self.index) {
+                (Some((name, _)), Some(_)) => name == self.name,
+                (None, None) => *field == self.name,
+                _ => false,
+            })
+    }
+
+    fn full_name(&self) -> String {
+        match self.index {
+            Some(index) => format!("{}@{index}", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Returns the field of the placeholder in the row, if it has a value.
+    fn lookup<'a>(&self, row: &'a Fields) -> Option<&'a Field> {
+        let field = row.get(self.name.as_str())?;
+        match (&field.entries, self.index) {
+            (Some(entries), Some(index)) => entries.get(&index),
+            (None, None) => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Returns the formatted value of a field, before padding.
+    fn format(&self, field: &Field) -> ColoredString {
+        if let Some(widget) = &field.widget {
+            return widget.clone();
+        }
+
+        let mut text = field.text.clone();
+        let mut color = field.color;
+
+        if self.signed {
+            if let Some(number) = field.number {
+                let theme = theme::current();
+                if number > 0.0 {
+                    text.insert(0, '+');
+                    color = Some(theme.positive);
+                } else if number < 0.0 {
+                    color = Some(theme.negative);
+                }
+            }
+        }
+
+        ColoredString::from_str(&text, self.color.or(color), None)
+    }
+}
+
+fn flush_literal(literal: &mut String, segments: &mut Vec<Segment>) {
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(std::mem::take(literal)));
+    }
+}
+
+fn has_all_fields(segments: &[Segment], row: &Fields) -> bool {
+    segments.iter().all(|segment| match segment {
+        Segment::Literal(_) => true,
+        Segment::Field(placeholder) => placeholder.lookup(row).is_some(),
+        Segment::Optional(_) => true,
+    })
+}
+
+fn pad(value: ColoredString, width: usize, align: Option<Align>) -> ColoredString {
+    let free = width.saturating_sub(value.len());
+    let left = match align.unwrap_or(Align::Left) {
+        Align::Left => 0,
+        Align::Right => free,
+        Align::Center => free / 2,
+    };
+
+    let mut padded = ColoredString::from_unformatted_str(&" ".repeat(left));
+    padded.join(&value);
+    padded.pad_to(width);
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn render(template: &str, rows: &[Fields]) -> Vec<String> {
+        Template::parse(template)
+            .expect("Template should be valid")
+            .render(rows)
+            .iter()
+            .map(|line| line.iter().map(|c| c.character()).collect())
+            .collect()
+    }
+
+    fn row(fields: &[(&'static str, Field)]) -> Fields {
+        fields.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_column_alignment() {
+        let rows = [
+            row(&[("name", Field::text("Ahri")), ("games", Field::text("3"))]),
+            row(&[
+                ("name", Field::text("Aurelion Sol")),
+                ("games", Field::text("12")),
+            ]),
+        ];
+
+        assert_eq!(
+            render("{name:<} - {games:>} games", &rows),
+            ["Ahri         -  3 games", "Aurelion Sol - 12 games"]
+        );
+    }
+
+    #[test]
+    fn test_signed_and_optional() {
+        let rows = [
+            row(&[("gd", Field::number(803.0, "803"))]),
+            row(&[("gd", Field::number(-943.0, "-943"))]),
+            row(&[]),
+        ];
+
+        assert_eq!(
+            render("KDA[ - GD: {gd:+}]", &rows),
+            ["KDA - GD: +803", "KDA - GD: -943", "KDA"]
+        );
+    }
+
+    #[test]
+    fn test_indexed_fields() {
+        let rows = [
+            row(&[(
+                "gd",
+                Field::indexed([
+                    (10, Field::number(120.0, "120")),
+                    (15, Field::number(-40.0, "-40")),
+                ]),
+            )]),
+            row(&[("gd", Field::indexed([(10, Field::number(0.0, "0"))]))]),
+        ];
+
+        assert_eq!(
+            render("{gd@10:+}[ / {gd@15:+}]", &rows),
+            ["+120 / -40", "0"]
+        );
+    }
+
+    #[test_case("{gd@15}", &["gd@<minute>"] => true ; "indexed field")]
+    #[test_case("{gd}", &["gd@<minute>"] => false ; "indexed field without index")]
+    #[test_case("{cs@15}", &["cs"] => false ; "index on a plain field")]
+    fn test_validate(template: &str, available: &[&str]) -> bool {
+        Template::parse(template)
+            .expect("Template should be valid")
+            .validate(available)
+            .is_ok()
+    }
+
+    #[test_case("{{literal}} [[x]]" => true ; "escapes")]
+    #[test_case("{name:<8+|blue}" => true ; "full spec")]
+    #[test_case("{name" => false ; "unclosed placeholder")]
+    #[test_case("[{name}" => false ; "unclosed group")]
+    #[test_case("name}" => false ; "unexpected brace")]
+    #[test_case("{name:abc}" => false ; "invalid width")]
+    #[test_case("{name@ten}" => false ; "invalid index")]
+    #[test_case("{name:|notacolor}" => false ; "invalid color")]
+    fn test_parse(template: &str) -> bool {
+        Template::parse(template).is_ok()
+    }
+}
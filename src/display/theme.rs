@@ -0,0 +1,309 @@
+//! Color themes used to render the sections.
+//!
+//! A theme is either one of the built-in themes, or a TOML file overriding some colors
+//! of a base theme. Colors can be written as a name (`blue`), an ANSI 256 index (`208`),
+//! an RGB triple (`255,128,0`) or a hex code (`#ff8000`).
+
+use riven::consts::Tier;
+use serde::Deserialize;
+use std::{fs, path::PathBuf, str::FromStr, sync::OnceLock};
+use termcolor::Color;
+use thiserror::Error;
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Names of the built-in themes.
+pub const BUILT_IN_THEMES: &[&str] = &["default", "colorblind", "monochrome"];
+
+#[derive(Error, Debug)]
+pub enum ThemeError {
+    #[error("Theme {0} not found (built-in themes: {themes})", themes = BUILT_IN_THEMES.join(", "))]
+    NotFound(String),
+
+    #[error("Failed to read theme file: {0}")]
+    Read(#[from] std::io::Error),
+
+    #[error("Failed to parse theme file: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("Invalid color {0:?}")]
+    InvalidColor(String),
+}
+
+/// Colors used for the different elements of the sections.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    /// Won games.
+    pub win: Color,
+    /// Lost games.
+    pub loss: Color,
+    /// Positive differences (e.g. gold difference).
+    pub positive: Color,
+    /// Negative differences.
+    pub negative: Color,
+    /// Section headers, `None` keeps the terminal color.
+    pub header: Option<Color>,
+    /// Separator below the section headers.
+    pub separator: Option<Color>,
+    /// Unfilled part of the bars.
+    pub bar_empty: Color,
+    /// Highlighted values, such as charts.
+    pub accent: Color,
+    /// Colors of the ranked tiers.
+    pub tiers: TierColors,
+}
+
+#[derive(Debug, Clone)]
+pub struct TierColors {
+    pub iron: Color,
+    pub bronze: Color,
+    pub silver: Color,
+    pub gold: Color,
+    pub platinum: Color,
+    pub emerald: Color,
+    pub diamond: Color,
+    pub master: Color,
+    pub grandmaster: Color,
+    pub challenger: Color,
+}
+
+impl TierColors {
+    pub const fn get(&self, tier: Tier) -> Option<Color> {
+        match tier {
+            Tier::IRON => Some(self.iron),
+            Tier::BRONZE => Some(self.bronze),
+            Tier::SILVER => Some(self.silver),
+            Tier::GOLD => Some(self.gold),
+            Tier::PLATINUM => Some(self.platinum),
+            Tier::EMERALD => Some(self.emerald),
+            Tier::DIAMOND => Some(self.diamond),
+            Tier::MASTER => Some(self.master),
+            Tier::GRANDMASTER => Some(self.grandmaster),
+            Tier::CHALLENGER => Some(self.challenger),
+            Tier::UNRANKED => None,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            win: Color::Blue,
+            loss: Color::Red,
+            positive: Color::Green,
+            negative: Color::Red,
+            header: None,
+            separator: None,
+            bar_empty: Color::White,
+            accent: Color::Cyan,
+            tiers: TierColors {
+                iron: Color::Ansi256(102),        // Dark gray
+                bronze: Color::Ansi256(130),      // Bronze
+                silver: Color::Ansi256(145),      // Silver
+                gold: Color::Ansi256(178),        // Gold
+                platinum: Color::Ansi256(80),     // Teal
+                emerald: Color::Ansi256(35),      // Emerald green
+                diamond: Color::Ansi256(69),      // Light blue
+                master: Color::Ansi256(99),       // Purple
+                grandmaster: Color::Ansi256(160), // Red
+                challenger: Color::Ansi256(220),  // Light gold
+            },
+        }
+    }
+}
+
+impl Theme {
+    /// Blue / orange palette, distinguishable with the most common color blindnesses.
+    fn colorblind() -> Self {
+        const BLUE: Color = Color::Ansi256(33);
+        const ORANGE: Color = Color::Ansi256(208);
+
+        Self {
+            win: BLUE,
+            loss: ORANGE,
+            positive: BLUE,
+            negative: ORANGE,
+            ..Self::default()
+        }
+    }
+
+    /// Grayscale palette, with the results told apart by their brightness.
+    fn monochrome() -> Self {
+        const BRIGHT: Color = Color::Ansi256(255);
+        const DIM: Color = Color::Ansi256(244);
+
+        Self {
+            win: BRIGHT,
+            loss: DIM,
+            positive: BRIGHT,
+            negative: DIM,
+            header: None,
+            separator: Some(DIM),
+            bar_empty: Color::Ansi256(238),
+            accent: BRIGHT,
+            tiers: TierColors {
+                iron: DIM,
+                bronze: DIM,
+                silver: DIM,
+                gold: DIM,
+                platinum: BRIGHT,
+                emerald: BRIGHT,
+                diamond: BRIGHT,
+                master: BRIGHT,
+                grandmaster: BRIGHT,
+                challenger: BRIGHT,
+            },
+        }
+    }
+
+    fn built_in(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            "colorblind" => Some(Self::colorblind()),
+            "monochrome" => Some(Self::monochrome()),
+            _ => None,
+        }
+    }
+
+    /// Loads a theme from its name or the path to a theme file.
+    /// Theme files are looked up in the `themes` folder of the configuration directory
+    /// if the name is neither a built-in theme nor an existing path.
+    pub fn load(name: &str) -> Result<Self, ThemeError> {
+        if let Some(theme) = Self::built_in(name) {
+            return Ok(theme);
+        }
+
+        let path = PathBuf::from(name);
+        let path = if path.is_file() {
+            path
+        } else {
+            dirs::config_dir()
+                .map(|dir| {
+                    dir.join("lolfetch")
+                        .join("themes")
+                        .join(format!("{name}.toml"))
+                })
+                .filter(|path| path.is_file())
+                .ok_or_else(|| ThemeError::NotFound(name.to_string()))?
+        };
+
+        info!("Loading theme from {}", path.display());
+        let file: ThemeFile = toml::from_str(&fs::read_to_string(path)?)?;
+        file.apply()
+    }
+}
+
+/// Theme file, every color is optional and defaults to the one of the base theme.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    base: Option<String>,
+    win: Option<String>,
+    loss: Option<String>,
+    positive: Option<String>,
+    negative: Option<String>,
+    header: Option<String>,
+    separator: Option<String>,
+    bar_empty: Option<String>,
+    accent: Option<String>,
+    #[serde(default)]
+    tiers: TierColorsFile,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TierColorsFile {
+    iron: Option<String>,
+    bronze: Option<String>,
+    silver: Option<String>,
+    gold: Option<String>,
+    platinum: Option<String>,
+    emerald: Option<String>,
+    diamond: Option<String>,
+    master: Option<String>,
+    grandmaster: Option<String>,
+    challenger: Option<String>,
+}
+
+impl ThemeFile {
+    fn apply(self) -> Result<Theme, ThemeError> {
+        let base = self.base.as_deref().unwrap_or("default");
+        let mut theme =
+            Theme::built_in(base).ok_or_else(|| ThemeError::NotFound(base.to_string()))?;
+
+        let overrides = [
+            (self.win, &mut theme.win),
+            (self.loss, &mut theme.loss),
+            (self.positive, &mut theme.positive),
+            (self.negative, &mut theme.negative),
+            (self.bar_empty, &mut theme.bar_empty),
+            (self.accent, &mut theme.accent),
+            (self.tiers.iron, &mut theme.tiers.iron),
+            (self.tiers.bronze, &mut theme.tiers.bronze),
+            (self.tiers.silver, &mut theme.tiers.silver),
+            (self.tiers.gold, &mut theme.tiers.gold),
+            (self.tiers.platinum, &mut theme.tiers.platinum),
+            (self.tiers.emerald, &mut theme.tiers.emerald),
+            (self.tiers.diamond, &mut theme.tiers.diamond),
+            (self.tiers.master, &mut theme.tiers.master),
+            (self.tiers.grandmaster, &mut theme.tiers.grandmaster),
+            (self.tiers.challenger, &mut theme.tiers.challenger),
+        ];
+        for (value, color) in overrides {
+            if let Some(value) = value {
+                *color = parse_color(&value)?;
+            }
+        }
+
+        if let Some(header) = self.header {
+            theme.header = Some(parse_color(&header)?);
+        }
+        if let Some(separator) = self.separator {
+            theme.separator = Some(parse_color(&separator)?);
+        }
+
+        Ok(theme)
+    }
+}
+
+/// Parses a color, accepting the `termcolor` formats as well as hex codes.
+pub fn parse_color(value: &str) -> Result<Color, ThemeError> {
+    let invalid = || ThemeError::InvalidColor(value.to_string());
+
+    match value.trim().strip_prefix('#') {
+        Some(hex) if hex.len() == 6 => {
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+            Ok(Color::Rgb(channel(0)?, channel(2)?, channel(4)?))
+        }
+        Some(_) => Err(invalid()),
+        None => Color::from_str(value.trim()).map_err(|_| invalid()),
+    }
+}
+
+/// Sets the theme used for the rest of the execution.
+pub fn set(theme: Theme) {
+    if THEME.set(theme).is_err() {
+        warn!("Theme was already set, ignoring");
+    }
+}
+
+/// Returns the current theme, or the default one if none was set.
+pub fn current() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("blue" => Some(Color::Blue) ; "name")]
+    #[test_case("208" => Some(Color::Ansi256(208)) ; "ansi 256")]
+    #[test_case("255,128,0" => Some(Color::Rgb(255, 128, 0)) ; "rgb triple")]
+    #[test_case("#FF8000" => Some(Color::Rgb(255, 128, 0)) ; "hex")]
+    #[test_case("#FF80" => None ; "short hex")]
+    #[test_case("orangeish" => None ; "unknown name")]
+    fn test_parse_color(value: &str) -> Option<Color> {
+        parse_color(value).ok()
+    }
+}
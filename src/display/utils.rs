@@ -1,9 +1,11 @@
 //! This module contains utilities regarding the display of the application.
 
+use super::theme;
 use lolfetch_color::ColoredString;
-use termcolor::Color;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub mod colors;
+pub mod widgets;
 
 /// Generate a two-part loading bar.
 pub fn generate_loading_bar(
@@ -25,10 +27,24 @@ pub fn generate_loading_bar(
             if i < percentage_filled {
                 Some(fill_color)
             } else {
-                Some(Color::White)
+                Some(theme::current().bar_empty)
             },
         );
     }
 
     bar
 }
+
+/// Returns the time elapsed since `timestamp` (in milliseconds since the epoch), e.g. `3d ago`.
+pub fn time_ago(timestamp: i64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as i64);
+    let minutes = (now - timestamp).max(0) / 60_000;
+
+    match minutes {
+        0..60 => format!("{minutes}m ago"),
+        60..1440 => format!("{}h ago", minutes / 60),
+        _ => format!("{}d ago", minutes / 1440),
+    }
+}
@@ -0,0 +1,249 @@
+//! Small visual widgets that can be used in the body of any section.
+
+use crate::display::theme;
+use lolfetch_color::{ColoredChar, ColoredString};
+use termcolor::Color;
+
+/// Blocks filling 0/8 to 7/8 of a cell, from the left.
+const PARTIAL_BLOCKS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// Blocks filling 1/8 to 8/8 of a cell, from the bottom.
+const SPARK_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Shades of the cells of a heatmap, from the fewest to the most occurrences.
+const HEAT_SHADES: [char; 4] = ['░', '▒', '▓', '█'];
+
+/// LP needed to go from a division to the next one.
+const LP_PER_DIVISION: i32 = 100;
+
+/// Generates a progress bar on the unfilled bar color of the theme,
+/// with a precision of an eighth of a cell.
+pub fn progress_bar(ratio: f64, width: usize, color: Color) -> ColoredString {
+    let empty = Some(theme::current().bar_empty);
+    let mut bar = ColoredString::new();
+
+    for (character, filled) in blocks(ratio, width) {
+        bar.push(ColoredChar::new(character, filled.then_some(color), empty));
+    }
+
+    bar
+}
+
+/// Generates a horizontal bar proportional to `value / max`, without background,
+/// meant to be stacked in rows to form a bar chart.
+pub fn horizontal_bar(value: f64, max: f64, width: usize, color: Color) -> ColoredString {
+    let ratio = if max > 0.0 { value / max } else { 0.0 };
+    let mut bar = ColoredString::new();
+
+    for (character, filled) in blocks(ratio, width) {
+        bar.push(ColoredChar::new(character, filled.then_some(color), None));
+    }
+
+    bar
+}
+
+/// Generates a sparkline, each value being scaled between the minimum and maximum values.
+pub fn sparkline(values: &[f64], color: Option<Color>) -> ColoredString {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    values
+        .iter()
+        .map(|value| {
+            let level = if max > min {
+                ((value - min) / (max - min) * (SPARK_BLOCKS.len() - 1) as f64).round() as usize
+            } else {
+                SPARK_BLOCKS.len() / 2
+            };
+            ColoredChar::new(SPARK_BLOCKS[level], color, None)
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Generates an area chart of `height` rows, each value being a column scaled between
+/// the minimum and maximum values. Rows are returned from the top.
+pub fn area_chart(values: &[f64], height: usize, color: Color) -> Vec<ColoredString> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let eighths = height * SPARK_BLOCKS.len();
+
+    // Height of each column in eighths of a cell, the lowest value still being visible
+    let levels = values
+        .iter()
+        .map(|value| {
+            if max > min {
+                1 + ((value - min) / (max - min) * (eighths - 1) as f64).round() as usize
+            } else {
+                eighths / 2
+            }
+        })
+        .collect::<Vec<_>>();
+
+    (0..height)
+        .rev()
+        .map(|row| {
+            levels
+                .iter()
+                .map(|level| match level.saturating_sub(row * 8).min(8) {
+                    0 => ColoredChar::new(' ', None, None),
+                    filled => ColoredChar::new(SPARK_BLOCKS[filled - 1], Some(color), None),
+                })
+                .collect::<Vec<_>>()
+                .into()
+        })
+        .collect()
+}
+
+/// Generates a chart of `2 * half_height` rows, each value being a column rising from the middle
+/// when positive and hanging from it when negative, scaled to the largest absolute value.
+/// Rows are returned from the top, with a precision of half a cell.
+pub fn diverging_chart(
+    values: &[f64],
+    half_height: usize,
+    positive: Color,
+    negative: Color,
+) -> Vec<ColoredString> {
+    let max = values.iter().map(|value| value.abs()).fold(0.0, f64::max);
+
+    // Length of each column in halves of a cell
+    let levels = values
+        .iter()
+        .map(|value| {
+            if max > 0.0 {
+                (value.abs() / max * (half_height * 2) as f64).round() as usize
+            } else {
+                0
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let row = |distance: usize, above: bool| -> ColoredString {
+        values
+            .iter()
+            .zip(&levels)
+            .map(|(value, level)| {
+                let filled = if (*value > 0.0) == above {
+                    level.saturating_sub(distance * 2).min(2)
+                } else {
+                    0
+                };
+                match (filled, above) {
+                    (0, _) => ColoredChar::new(' ', None, None),
+                    (1, true) => ColoredChar::new('▄', Some(positive), None),
+                    (1, false) => ColoredChar::new('▀', Some(negative), None),
+                    (_, true) => ColoredChar::new('█', Some(positive), None),
+                    (_, false) => ColoredChar::new('█', Some(negative), None),
+                }
+            })
+            .collect::<Vec<_>>()
+            .into()
+    };
+
+    (0..half_height)
+        .rev()
+        .map(|distance| row(distance, true))
+        .chain((0..half_height).map(|distance| row(distance, false)))
+        .collect()
+}
+
+/// Generates a heatmap of `counts`, each cell being shaded relative to the largest count.
+/// Empty cells are dots of the unfilled bar color of the theme.
+pub fn heatmap(counts: &[Vec<u32>], color: Color) -> Vec<ColoredString> {
+    let empty = theme::current().bar_empty;
+    let max = counts.iter().flatten().copied().max().unwrap_or(0);
+
+    counts
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&count| {
+                    if count == 0 {
+                        return ColoredChar::new('·', Some(empty), None);
+                    }
+                    let shade = (f64::from(count) / f64::from(max) * HEAT_SHADES.len() as f64)
+                        .ceil() as usize;
+                    ColoredChar::new(
+                        HEAT_SHADES[shade.clamp(1, HEAT_SHADES.len()) - 1],
+                        Some(color),
+                        None,
+                    )
+                })
+                .collect::<Vec<_>>()
+                .into()
+        })
+        .collect()
+}
+
+/// Generates the progress towards the next division, followed by the LP count.
+pub fn lp_progress(lp: i32, width: usize, color: Color) -> ColoredString {
+    let mut progress = progress_bar(f64::from(lp) / f64::from(LP_PER_DIVISION), width, color);
+    progress.push_str(&format!(" {lp}/{LP_PER_DIVISION} LP"), Some(color), None);
+    progress
+}
+
+/// Returns the cells of a bar filled at `ratio`, and whether each cell is (partly) filled.
+fn blocks(ratio: f64, width: usize) -> impl Iterator<Item = (char, bool)> {
+    let eighths = (ratio.clamp(0.0, 1.0) * (width * 8) as f64).round() as usize;
+
+    (0..width).map(move |cell| match eighths.saturating_sub(cell * 8).min(8) {
+        8 => ('█', true),
+        0 => (' ', false),
+        partial => (PARTIAL_BLOCKS[partial], true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn characters(string: &ColoredString) -> String {
+        string.iter().map(ColoredChar::character).collect()
+    }
+
+    #[test_case(0.0, 4 => "    " ; "empty")]
+    #[test_case(1.0, 4 => "████" ; "full")]
+    #[test_case(0.5, 3 => "█▌ " ; "half")]
+    #[test_case(0.3, 5 => "█▌   " ; "partial")]
+    #[test_case(1.5, 2 => "██" ; "overflow")]
+    fn test_progress_bar(ratio: f64, width: usize) -> String {
+        characters(&progress_bar(ratio, width, Color::Blue))
+    }
+
+    #[test_case(&[0.0, 1.0, 2.0], 2 => vec![" ▁█", "▁██"] ; "increasing")]
+    #[test_case(&[5.0, 5.0], 2 => vec!["  ", "██"] ; "flat")]
+    fn test_area_chart(values: &[f64], height: usize) -> Vec<String> {
+        area_chart(values, height, Color::Blue)
+            .iter()
+            .map(characters)
+            .collect()
+    }
+
+    #[test_case(&[4.0, 1.0, -2.0, -4.0], 2 => vec!["█   ", "█▄  ", "  ██", "   █"] ; "both signs")]
+    #[test_case(&[0.0, 0.0], 1 => vec!["  ", "  "] ; "zero")]
+    fn test_diverging_chart(values: &[f64], half_height: usize) -> Vec<String> {
+        diverging_chart(values, half_height, Color::Green, Color::Red)
+            .iter()
+            .map(characters)
+            .collect()
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let counts = [vec![0, 1, 2], vec![3, 4, 8]];
+        let rows = heatmap(&counts, Color::Red)
+            .iter()
+            .map(characters)
+            .collect::<Vec<_>>();
+
+        assert_eq!(rows, ["·░░", "▒▒█"]);
+    }
+
+    #[test_case(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0] => "▁▂▃▄▅▆▇█" ; "linear")]
+    #[test_case(&[3.0, 3.0] => "▅▅" ; "flat")]
+    #[test_case(&[] => "" ; "empty")]
+    fn test_sparkline(values: &[f64]) -> String {
+        characters(&sparkline(values, None))
+    }
+}
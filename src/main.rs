@@ -14,6 +14,7 @@ mod data;
 mod display;
 mod logging;
 mod models;
+mod tui;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -1,5 +1,13 @@
 //! Defines data structures for the application.
 
+pub mod champion_pool;
 pub mod champion_stats;
+pub mod form;
+pub mod lane_diff;
+pub mod loadout;
 pub mod matches;
 pub mod ranked;
+pub mod summary;
+pub mod teammates;
+pub mod timeline;
+pub mod trends;
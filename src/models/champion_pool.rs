@@ -0,0 +1,99 @@
+//! Diversity of the champions played.
+
+use std::fmt;
+
+/// Share of the games on the most played champion from which the player is a one-trick.
+const ONE_TRICK_SHARE: f64 = 0.5;
+
+/// Share of the games on the comfort picks from which the pool is focused.
+const FOCUSED_SHARE: f64 = 0.7;
+
+/// Number of most played champions considered as comfort picks.
+pub const COMFORT_PICKS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolKind {
+    /// Most games on a single champion.
+    OneTrick,
+    /// Most games on the comfort picks.
+    Focused,
+    Wide,
+}
+
+impl fmt::Display for PoolKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OneTrick => write!(f, "One-trick"),
+            Self::Focused => write!(f, "Focused pool"),
+            Self::Wide => write!(f, "Wide pool"),
+        }
+    }
+}
+
+/// Spread of the games over the champions played.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoolSummary {
+    pub unique_champions: usize,
+    /// Share of the games on the most played champion.
+    pub top_share: f64,
+    /// Share of the games on the comfort picks.
+    pub comfort_share: f64,
+    /// Inverse Simpson index, the number of champions that, played equally, would give the same spread.
+    pub diversity: f64,
+    pub kind: PoolKind,
+}
+
+impl PoolSummary {
+    /// Summarizes the games played on each champion, sorted from the most played.
+    pub fn new(games: &[u32]) -> Option<Self> {
+        let total = f64::from(games.iter().sum::<u32>());
+        if total == 0.0 {
+            return None;
+        }
+
+        let share = |games: &[u32]| f64::from(games.iter().sum::<u32>()) / total;
+        let top_share = share(&games[..1]);
+        let comfort_share = share(&games[..games.len().min(COMFORT_PICKS)]);
+        let concentration = games
+            .iter()
+            .map(|&games| (f64::from(games) / total).powi(2))
+            .sum::<f64>();
+
+        let kind = if top_share >= ONE_TRICK_SHARE {
+            PoolKind::OneTrick
+        } else if comfort_share >= FOCUSED_SHARE {
+            PoolKind::Focused
+        } else {
+            PoolKind::Wide
+        };
+
+        Some(Self {
+            unique_champions: games.len(),
+            top_share,
+            comfort_share,
+            diversity: 1.0 / concentration,
+            kind,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&[6, 2, 2] => Some(PoolKind::OneTrick) ; "one trick")]
+    #[test_case(&[4, 2, 2, 1, 1] => Some(PoolKind::Focused) ; "focused")]
+    #[test_case(&[2, 2, 2, 2, 2] => Some(PoolKind::Wide) ; "wide")]
+    #[test_case(&[] => None ; "no games")]
+    fn test_kind(games: &[u32]) -> Option<PoolKind> {
+        PoolSummary::new(games).map(|summary| summary.kind)
+    }
+
+    #[test_case(&[5] => 1.0 ; "single champion")]
+    #[test_case(&[2, 2, 2, 2] => 4.0 ; "equal games")]
+    #[test_case(&[3, 1] => 1.6 ; "uneven games")]
+    fn test_diversity(games: &[u32]) -> f64 {
+        PoolSummary::new(games).unwrap().diversity
+    }
+}
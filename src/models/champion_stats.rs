@@ -1,4 +1,7 @@
-use super::matches::{GameResult, Kda, MatchPlayerInfo};
+use super::{
+    lane_diff::LaneDiffTotals,
+    matches::{GameResult, Kda, MatchPlayerInfo, Performance},
+};
 
 /// Champion statistics
 pub struct GameStats {
@@ -7,16 +10,20 @@ pub struct GameStats {
     time_played: i64,
     minions_killed: i32,
     kda: Kda,
+    lane_diffs: LaneDiffTotals,
+    performance: Performance,
 }
 
 impl GameStats {
-    pub const fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             wins: 0,
             losses: 0,
             time_played: 0,
             minions_killed: 0,
             kda: Kda(0, 0, 0),
+            lane_diffs: LaneDiffTotals::new(),
+            performance: Performance::default(),
         }
     }
 
@@ -28,18 +35,76 @@ impl GameStats {
         self.kda.get_kda()
     }
 
+    pub const fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    pub const fn losses(&self) -> u32 {
+        self.losses
+    }
+
     pub const fn total_games(&self) -> u32 {
         self.wins + self.losses
     }
 
+    pub const fn lane_diffs(&self) -> &LaneDiffTotals {
+        &self.lane_diffs
+    }
+
     pub fn cspm(&self) -> f64 {
-        f64::from(self.minions_killed) / (self.time_played as f64 / 60.0)
+        f64::from(self.minions_killed) / self.minutes_played()
+    }
+
+    /// Share of the kills of the team the player took part in.
+    pub fn kill_participation(&self) -> Option<f64> {
+        let Kda(kills, _, assists) = self.kda;
+        ratio(kills + assists, self.performance.team_kills)
+    }
+
+    /// Share of the damage to champions of the team dealt by the player.
+    pub fn damage_share(&self) -> Option<f64> {
+        ratio(
+            self.performance.damage_to_champions,
+            self.performance.team_damage_to_champions,
+        )
+    }
+
+    /// Damage to champions per minute.
+    pub fn dpm(&self) -> f64 {
+        f64::from(self.performance.damage_to_champions) / self.minutes_played()
+    }
+
+    /// Gold earned per minute.
+    pub fn gpm(&self) -> f64 {
+        f64::from(self.performance.gold_earned) / self.minutes_played()
+    }
+
+    /// Vision score per minute.
+    pub fn vspm(&self) -> f64 {
+        f64::from(self.performance.vision_score) / self.minutes_played()
+    }
+
+    /// Control wards bought per game.
+    pub fn control_wards(&self) -> f64 {
+        f64::from(self.performance.control_wards_bought) / f64::from(self.total_games())
+    }
+
+    /// Share of the epic monsters of the team the player took part in.
+    pub fn objective_participation(&self) -> Option<f64> {
+        let (takedowns, total) = self.performance.epic_monsters?;
+        ratio(takedowns, total)
+    }
+
+    fn minutes_played(&self) -> f64 {
+        self.time_played as f64 / 60.0
     }
 
     pub fn add_game(&mut self, match_info: &MatchPlayerInfo) {
         self.kda.add(&match_info.kda);
         self.time_played += i64::from(match_info.time_played);
         self.minions_killed += match_info.minions_killed;
+        self.lane_diffs.add_game(&match_info.lane_diffs);
+        self.performance.add(&match_info.performance);
         match match_info.game_result {
             GameResult::Win => self.wins += 1,
             GameResult::Loss => self.losses += 1,
@@ -54,3 +119,57 @@ impl From<&MatchPlayerInfo> for GameStats {
         stats
     }
 }
+
+fn ratio(value: i32, total: i32) -> Option<f64> {
+    (total > 0).then(|| f64::from(value) / f64::from(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::matches::TeamPosition;
+    use riven::consts::Champion;
+
+    fn game(kills: i32, assists: i32, performance: Performance) -> MatchPlayerInfo {
+        MatchPlayerInfo {
+            kda: Kda(kills, 2, assists),
+            minions_killed: 200,
+            time_played: 1800,
+            performance,
+            ..MatchPlayerInfo::test(Champion::AHRI, TeamPosition::Mid, GameResult::Win)
+        }
+    }
+
+    #[test]
+    fn test_aggregated_performance() {
+        let mut stats = GameStats::new();
+        stats.add_game(&game(
+            5,
+            5,
+            Performance {
+                team_kills: 20,
+                damage_to_champions: 30_000,
+                team_damage_to_champions: 100_000,
+                epic_monsters: Some((2, 4)),
+                ..Performance::default()
+            },
+        ));
+        stats.add_game(&game(
+            2,
+            8,
+            Performance {
+                team_kills: 30,
+                damage_to_champions: 15_000,
+                team_damage_to_champions: 50_000,
+                epic_monsters: None,
+                ..Performance::default()
+            },
+        ));
+
+        assert_eq!(stats.kill_participation(), Some(0.4));
+        assert_eq!(stats.damage_share(), Some(0.3));
+        assert_eq!(stats.dpm(), 750.0);
+        // Only the games with the information are used
+        assert_eq!(stats.objective_participation(), Some(0.5));
+    }
+}
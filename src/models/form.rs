@@ -0,0 +1,130 @@
+//! Streaks and play sessions, computed from the order of the games.
+
+use super::matches::{GameResult, MatchInfo, ParticipantGetter, TeamGetter};
+use riven::models::summoner_v4::Summoner;
+
+/// Time between two games after which they are considered to be in different sessions.
+const SESSION_GAP_MS: i64 = 60 * 60 * 1000;
+
+/// Outcome of a game, with the time it was played at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameOutcome {
+    /// Start of the game, in milliseconds since the epoch.
+    pub start: i64,
+    /// End of the game, in milliseconds since the epoch.
+    pub end: i64,
+    pub result: GameResult,
+}
+
+impl GameOutcome {
+    /// Returns `None` for remakes and games the summoner is not part of.
+    pub fn from_match_info(match_info: &MatchInfo, summoner: &Summoner) -> Option<Self> {
+        if match_info.is_remake() {
+            return None;
+        }
+
+        let info = &match_info.info;
+        let participant = info.get_participant(summoner).ok()?;
+        let team = info.get_my_team(participant).ok()?;
+
+        Some(Self {
+            start: info.game_creation,
+            end: match_info.end_timestamp(),
+            result: if team.win {
+                GameResult::Win
+            } else {
+                GameResult::Loss
+            },
+        })
+    }
+}
+
+/// Consecutive games with the same result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Streak {
+    pub result: GameResult,
+    pub games: u32,
+}
+
+/// Returns the streak the player is currently on, `games` being sorted from the most recent.
+pub fn current_streak(games: &[GameOutcome]) -> Option<Streak> {
+    let result = games.first()?.result;
+    let games = games
+        .iter()
+        .take_while(|game| game.result == result)
+        .count() as u32;
+
+    Some(Streak { result, games })
+}
+
+/// Groups the games into play sessions, a new session starting after an hour without playing.
+/// Both the sessions and their games keep the order of `games`, sorted from the most recent.
+pub fn sessions(games: &[GameOutcome]) -> Vec<&[GameOutcome]> {
+    let mut sessions = Vec::new();
+    let mut start = 0;
+
+    for (index, pair) in games.windows(2).enumerate() {
+        let (newer, older) = (pair[0], pair[1]);
+        if newer.start - older.end > SESSION_GAP_MS {
+            sessions.push(&games[start..=index]);
+            start = index + 1;
+        }
+    }
+    if start < games.len() {
+        sessions.push(&games[start..]);
+    }
+
+    sessions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const MINUTE: i64 = 60 * 1000;
+
+    /// Games of 30 minutes, from the most recent, each starting `gap` minutes after the end of the previous one.
+    fn games(results: &str, gaps: &[i64]) -> Vec<GameOutcome> {
+        let mut start = 0;
+        let mut games = results
+            .chars()
+            .enumerate()
+            .map(|(index, result)| {
+                let game = GameOutcome {
+                    start,
+                    end: start + 30 * MINUTE,
+                    result: if result == 'W' {
+                        GameResult::Win
+                    } else {
+                        GameResult::Loss
+                    },
+                };
+                start = game.end + gaps.get(index).copied().unwrap_or(5) * MINUTE;
+                game
+            })
+            .collect::<Vec<_>>();
+        games.reverse();
+        games
+    }
+
+    // Results are written from the oldest to the most recent
+    #[test_case("WWLLLL" => Some((GameResult::Loss, 4)) ; "losing streak")]
+    #[test_case("LW" => Some((GameResult::Win, 1)) ; "single win")]
+    #[test_case("WWW" => Some((GameResult::Win, 3)) ; "only wins")]
+    #[test_case("" => None ; "no games")]
+    fn test_current_streak(results: &str) -> Option<(GameResult, u32)> {
+        current_streak(&games(results, &[])).map(|streak| (streak.result, streak.games))
+    }
+
+    #[test_case("WLWLW", &[] => vec![5] ; "single session")]
+    #[test_case("WLWLW", &[5, 120, 5, 5] => vec![3, 2] ; "two sessions")]
+    #[test_case("WLW", &[61, 90] => vec![1, 1, 1] ; "one game per session")]
+    #[test_case("", &[] => Vec::<usize>::new() ; "no games")]
+    fn test_sessions(results: &str, gaps: &[i64]) -> Vec<usize> {
+        sessions(&games(results, gaps))
+            .iter()
+            .map(|session| session.len())
+            .collect()
+    }
+}
@@ -0,0 +1,226 @@
+//! Differences with the lane opponent over the course of a game.
+
+use super::matches::{LaneOpponentGetter, MatchInfo, ParticipantGetter};
+use riven::models::{match_v5, summoner_v4::Summoner};
+use std::{collections::BTreeMap, ops::AddAssign};
+
+/// Minute of the game, as used to index the lane differences.
+pub type Minute = u32;
+
+/// Difference with the lane opponent at a given time, positive when ahead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LaneDiff {
+    pub gold: i32,
+    /// Lane and jungle minions
+    pub cs: i32,
+    pub xp: i32,
+    pub level: i32,
+}
+
+impl LaneDiff {
+    fn between(me: &match_v5::ParticipantFrame, opponent: &match_v5::ParticipantFrame) -> Self {
+        Self {
+            gold: me.total_gold - opponent.total_gold,
+            cs: (me.minions_killed + me.jungle_minions_killed)
+                - (opponent.minions_killed + opponent.jungle_minions_killed),
+            xp: me.xp - opponent.xp,
+            level: me.level - opponent.level,
+        }
+    }
+}
+
+impl AddAssign for LaneDiff {
+    fn add_assign(&mut self, other: Self) {
+        self.gold += other.gold;
+        self.cs += other.cs;
+        self.xp += other.xp;
+        self.level += other.level;
+    }
+}
+
+/// Average difference with the lane opponent over several games.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AverageLaneDiff {
+    pub gold: f64,
+    pub cs: f64,
+    pub xp: f64,
+    pub level: f64,
+}
+
+impl From<LaneDiff> for AverageLaneDiff {
+    fn from(diff: LaneDiff) -> Self {
+        Self {
+            gold: f64::from(diff.gold),
+            cs: f64::from(diff.cs),
+            xp: f64::from(diff.xp),
+            level: f64::from(diff.level),
+        }
+    }
+}
+
+/// Sums of the lane differences of several games, for each minute.
+#[derive(Debug, Clone, Default)]
+pub struct LaneDiffTotals {
+    /// Sum of the differences and number of games that lasted until the minute.
+    totals: BTreeMap<Minute, (LaneDiff, u32)>,
+}
+
+impl LaneDiffTotals {
+    pub const fn new() -> Self {
+        Self {
+            totals: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_game(&mut self, lane_diffs: &BTreeMap<Minute, LaneDiff>) {
+        for (&minute, &diff) in lane_diffs {
+            let (total, games) = self.totals.entry(minute).or_default();
+            *total += diff;
+            *games += 1;
+        }
+    }
+
+    pub fn average(&self, minute: Minute) -> Option<AverageLaneDiff> {
+        let (total, games) = self.totals.get(&minute)?;
+        let games = f64::from(*games);
+
+        Some(AverageLaneDiff {
+            gold: f64::from(total.gold) / games,
+            cs: f64::from(total.cs) / games,
+            xp: f64::from(total.xp) / games,
+            level: f64::from(total.level) / games,
+        })
+    }
+
+    /// Returns the average of every minute reached in at least one game.
+    pub fn averages(&self) -> impl Iterator<Item = (Minute, AverageLaneDiff)> + '_ {
+        self.totals
+            .keys()
+            .filter_map(|&minute| self.average(minute).map(|average| (minute, average)))
+    }
+}
+
+pub trait LaneDiffGetter {
+    /// Returns the difference with the lane opponent at every minute of the game,
+    /// empty if the game has no timeline or no lane opponent.
+    fn get_lane_diffs(&self, summoner: &Summoner) -> BTreeMap<Minute, LaneDiff>;
+}
+
+impl LaneDiffGetter for MatchInfo {
+    fn get_lane_diffs(&self, summoner: &Summoner) -> BTreeMap<Minute, LaneDiff> {
+        let (Some(timeline), Some(participants)) =
+            (self.timeline.as_ref(), self.get_lane_participants(summoner))
+        else {
+            return BTreeMap::new();
+        };
+        let last_minute = timeline
+            .frames
+            .last()
+            .map_or(0, |frame| frame.timestamp / 60_000) as Minute;
+
+        (1..=last_minute)
+            .filter_map(|minute| diff_at(timeline, participants, minute).map(|diff| (minute, diff)))
+            .collect()
+    }
+}
+
+/// Returns the difference between the participants (me, opponent) at `minute`.
+fn diff_at(
+    timeline: &match_v5::InfoTimeLine,
+    (me, opponent): (i32, i32),
+    minute: Minute,
+) -> Option<LaneDiff> {
+    let frames = timeline
+        .get_frame(minute as i32 * 60)?
+        .participant_frames
+        .as_ref()?;
+
+    Some(LaneDiff::between(frames.get(&me)?, frames.get(&opponent)?))
+}
+
+trait LaneParticipantsGetter {
+    /// Returns the participant IDs of the summoner and of their lane opponent.
+    fn get_lane_participants(&self, summoner: &Summoner) -> Option<(i32, i32)>;
+}
+
+impl LaneParticipantsGetter for MatchInfo {
+    fn get_lane_participants(&self, summoner: &Summoner) -> Option<(i32, i32)> {
+        let participant = self.info.get_participant(summoner).ok()?;
+        let opponent = self.info.get_lane_opponent(participant)?;
+
+        Some((participant.participant_id, opponent.participant_id))
+    }
+}
+
+pub trait FrameGetter {
+    /// Returns the frame closest to `seconds`, `None` if the game ended before.
+    fn get_frame(&self, seconds: i32) -> Option<&match_v5::FramesTimeLine>;
+}
+
+impl FrameGetter for match_v5::InfoTimeLine {
+    fn get_frame(&self, seconds: i32) -> Option<&match_v5::FramesTimeLine> {
+        let timestamp = seconds * 1000;
+        if self.frames.last()?.timestamp < timestamp {
+            return None;
+        }
+
+        self.frames
+            .iter()
+            .min_by_key(|frame| (frame.timestamp - timestamp).abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn timeline(timestamps: &[i32]) -> match_v5::InfoTimeLine {
+        match_v5::InfoTimeLine {
+            end_of_game_result: None,
+            frame_interval: 60_000,
+            game_id: None,
+            participants: None,
+            frames: timestamps
+                .iter()
+                .map(|&timestamp| match_v5::FramesTimeLine {
+                    events: Vec::new(),
+                    participant_frames: None,
+                    timestamp,
+                })
+                .collect(),
+        }
+    }
+
+    #[test_case(&[0, 60_021, 120_043, 180_058], 120 => Some(120_043) ; "slightly after")]
+    #[test_case(&[0, 59_987, 119_990, 180_004], 120 => Some(119_990) ; "slightly before")]
+    #[test_case(&[0, 60_021, 120_043, 150_500], 150 => Some(150_500) ; "end of game")]
+    #[test_case(&[0, 60_021, 120_043, 150_500], 180 => None ; "after the end of game")]
+    #[test_case(&[], 60 => None ; "no frames")]
+    fn test_get_frame(timestamps: &[i32], seconds: i32) -> Option<i32> {
+        timeline(timestamps)
+            .get_frame(seconds)
+            .map(|frame| frame.timestamp)
+    }
+
+    #[test]
+    fn test_average_lane_diff() {
+        let diff = |gold, cs| LaneDiff {
+            gold,
+            cs,
+            xp: 0,
+            level: 0,
+        };
+        let mut totals = LaneDiffTotals::default();
+        totals.add_game(&BTreeMap::from([(10, diff(300, 4)), (15, diff(500, 10))]));
+        totals.add_game(&BTreeMap::from([(10, diff(-100, -2))]));
+
+        let at_10 = totals.average(10).expect("Both games reached 10 minutes");
+        assert_eq!((at_10.gold, at_10.cs), (100.0, 1.0));
+
+        let at_15 = totals.average(15).expect("One game reached 15 minutes");
+        assert_eq!((at_15.gold, at_15.cs), (500.0, 10.0));
+
+        assert_eq!(totals.average(20), None);
+    }
+}
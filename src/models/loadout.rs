@@ -0,0 +1,141 @@
+//! Items, runes and summoner spells of a player.
+
+use super::matches::GameResult;
+use riven::models::match_v5::Participant;
+use std::{collections::HashMap, hash::Hash};
+
+/// Keystone and secondary rune tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RunePage {
+    pub keystone: i32,
+    pub secondary_tree: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Loadout {
+    /// Items at the end of the game, without the trinket and the empty slots.
+    pub items: Vec<i32>,
+    pub runes: Option<RunePage>,
+    pub summoner_spells: [i32; 2],
+}
+
+impl Loadout {
+    pub fn from_participant(participant: &Participant) -> Self {
+        let style = |description: &str| {
+            participant
+                .perks
+                .styles
+                .iter()
+                .find(|style| style.description == description)
+        };
+        let runes = style("primaryStyle")
+            .and_then(|primary| primary.selections.first())
+            .zip(style("subStyle"))
+            .map(|(keystone, secondary)| RunePage {
+                keystone: keystone.perk,
+                secondary_tree: secondary.style,
+            });
+
+        Self {
+            items: [
+                participant.item0,
+                participant.item1,
+                participant.item2,
+                participant.item3,
+                participant.item4,
+                participant.item5,
+            ]
+            .into_iter()
+            .filter(|&item| item != 0)
+            .collect(),
+            runes,
+            summoner_spells: [participant.summoner1_id, participant.summoner2_id],
+        }
+    }
+}
+
+/// Number of games and wins of each choice (build, rune page...).
+#[derive(Debug, Clone)]
+pub struct Choices<K> {
+    counts: HashMap<K, (u32, u32)>,
+}
+
+impl<K: Eq + Hash + Ord> Choices<K> {
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, choice: K, result: GameResult) {
+        let (games, wins) = self.counts.entry(choice).or_insert((0, 0));
+        *games += 1;
+        if result == GameResult::Win {
+            *wins += 1;
+        }
+    }
+
+    /// Returns the most common choice with its games and wins, ties going to the most wins.
+    pub fn most_common(&self) -> Option<(&K, u32, u32)> {
+        self.counts
+            .iter()
+            .max_by(|(a, (a_games, a_wins)), (b, (b_games, b_wins))| {
+                a_games
+                    .cmp(b_games)
+                    .then(a_wins.cmp(b_wins))
+                    .then_with(|| b.cmp(a))
+            })
+            .map(|(choice, (games, wins))| (choice, *games, *wins))
+    }
+
+    /// Returns the choice the most games were won with.
+    pub fn most_wins(&self) -> Option<&K> {
+        self.counts
+            .iter()
+            .filter(|(_, (_, wins))| *wins > 0)
+            .max_by(|(a, (a_games, a_wins)), (b, (b_games, b_wins))| {
+                a_wins
+                    .cmp(b_wins)
+                    .then(b_games.cmp(a_games))
+                    .then_with(|| b.cmp(a))
+            })
+            .map(|(choice, _)| choice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn choices(games: &[(&'static str, bool)]) -> Choices<&'static str> {
+        let mut choices = Choices::new();
+        for &(choice, won) in games {
+            choices.add(
+                choice,
+                if won {
+                    GameResult::Win
+                } else {
+                    GameResult::Loss
+                },
+            );
+        }
+        choices
+    }
+
+    #[test_case(&[("a", false), ("b", true), ("a", false)] => Some(("a", 2, 0)) ; "most games")]
+    #[test_case(&[("a", false), ("b", true)] => Some(("b", 1, 1)) ; "ties go to the most wins")]
+    #[test_case(&[] => None ; "no games")]
+    fn test_most_common(games: &[(&'static str, bool)]) -> Option<(&'static str, u32, u32)> {
+        choices(games)
+            .most_common()
+            .map(|(choice, games, wins)| (*choice, games, wins))
+    }
+
+    #[test_case(&[("a", false), ("a", false), ("a", true), ("b", true), ("b", true)] => Some("b") ; "most wins")]
+    #[test_case(&[("a", true), ("b", true), ("b", false)] => Some("a") ; "ties go to the fewest games")]
+    #[test_case(&[("a", false)] => None ; "no wins")]
+    fn test_most_wins(games: &[(&'static str, bool)]) -> Option<&'static str> {
+        choices(games).most_wins().copied()
+    }
+}
@@ -1,13 +1,20 @@
 use riven::{
-    consts::Champion,
+    consts::{Champion, Queue},
     models::{
         match_v5::{self, Participant, Team},
         summoner_v4::{self, Summoner},
     },
 };
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
 use thiserror::Error;
 
+use super::{
+    lane_diff::{LaneDiff, LaneDiffGetter, Minute},
+    loadout::Loadout,
+};
 use crate::api::tooling::{ranked_schedule::get_split_from_patch, static_data::get_latest_patch};
 
 pub type MatchId = String;
@@ -27,6 +34,13 @@ impl MatchInfo {
         self.info.game_duration < MINUTES_UNTIL_REMAKE * 60
     }
 
+    /// End of the game, in milliseconds since the epoch.
+    pub fn end_timestamp(&self) -> i64 {
+        self.info
+            .game_end_timestamp
+            .unwrap_or(self.info.game_creation + self.info.game_duration * 1000)
+    }
+
     pub async fn is_current_split(&self) -> bool {
         get_split_from_patch(&self.info.game_version).expect("Failed to get split from patch")
             == get_split_from_patch(get_latest_patch().await)
@@ -65,8 +79,14 @@ pub struct MatchPlayerInfo {
     pub game_result: GameResult,
     /// Position in the team.
     pub team_position: TeamPosition,
-    /// GD@15
-    pub gold_diff_15: Option<i32>,
+    /// Champion played by the lane opponent.
+    pub lane_opponent: Option<Champion>,
+    /// Differences with the lane opponent at each minute of the game.
+    pub lane_diffs: BTreeMap<Minute, LaneDiff>,
+    /// Damage, gold, vision and objectives.
+    pub performance: Performance,
+    /// Items, runes and summoner spells.
+    pub loadout: Loadout,
 }
 
 impl MatchPlayerInfo {
@@ -77,7 +97,6 @@ impl MatchPlayerInfo {
         let participant = match_data.info.get_participant(summoner)?;
         let team = match_data.info.get_my_team(participant)?;
         let max_time = match_data.info.get_max_time()?;
-        let gold_diff = match_data.get_gold_diff(summoner, 60 * 15);
         let game_result = if team.win {
             GameResult::Win
         } else {
@@ -94,57 +113,46 @@ impl MatchPlayerInfo {
             time_played: max_time,
             game_result,
             team_position: participant.team_position.clone().try_into()?,
-            gold_diff_15: gold_diff,
+            lane_opponent: match_data
+                .info
+                .get_lane_opponent(participant)
+                .and_then(|opponent| opponent.champion().ok()),
+            lane_diffs: match_data.get_lane_diffs(summoner),
+            performance: Performance::new(&match_data.info, participant, team),
+            loadout: Loadout::from_participant(participant),
         })
     }
 }
 
-pub trait GoldDiffGetter {
-    fn get_gold_diff(&self, summoner: &Summoner, seconds: i32) -> Option<i32>;
-}
-
-impl GoldDiffGetter for MatchInfo {
-    fn get_gold_diff(&self, summoner: &Summoner, seconds: i32) -> Option<i32> {
-        let participant = self.info.get_participant(summoner).ok()?;
-        let position = TeamPosition::try_from(participant.team_position.clone()).ok()?;
-
-        let enemy_laner = self.info.participants.iter().find(|p| {
-            p.team_position == position.to_riot_api_string() && p.team_id != participant.team_id
-        })?;
-
-        let frame = self
-            .timeline
-            .as_ref()?
-            .get_frame(seconds)?
-            .participant_frames
-            .clone()?;
-
-        let enemy = frame.get(&enemy_laner.participant_id)?;
-        let me = frame.get(&participant.participant_id)?;
-
-        Some(me.total_gold - enemy.total_gold)
-    }
-}
-
-pub trait FrameGetter {
-    fn get_frame(&self, seconds: i32) -> Option<&match_v5::FramesTimeLine>;
-}
-
-impl FrameGetter for match_v5::InfoTimeLine {
-    fn get_frame(&self, seconds: i32) -> Option<&match_v5::FramesTimeLine> {
-        let sec_timestamp = seconds * 1000;
-        self.frames
-            .iter()
-            .find(|&frame| frame.timestamp > sec_timestamp)
+#[cfg(test)]
+impl MatchPlayerInfo {
+    /// Returns a 25 minutes game with a 1/1/1 KDA and 150 CS, without lane opponent.
+    pub fn test(champion: Champion, team_position: TeamPosition, game_result: GameResult) -> Self {
+        Self {
+            champion,
+            kda: Kda(1, 1, 1),
+            minions_killed: 150,
+            time_played: 1500,
+            game_result,
+            team_position,
+            lane_opponent: None,
+            lane_diffs: BTreeMap::new(),
+            performance: Performance::default(),
+            loadout: Loadout::default(),
+        }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, clap::ValueEnum)]
 pub enum TeamPosition {
     Top,
+    #[value(alias = "jgl")]
     Jungle,
+    #[value(alias = "middle")]
     Mid,
+    #[value(alias = "adc")]
     Bot,
+    #[value(alias = "sup")]
     Support,
 }
 
@@ -170,7 +178,7 @@ impl TryFrom<String> for TeamPosition {
 }
 
 impl TeamPosition {
-    pub fn to_riot_api_string(&self) -> &'static str {
+    pub const fn to_riot_api_string(self) -> &'static str {
         match self {
             Self::Top => "TOP",
             Self::Jungle => "JUNGLE",
@@ -193,7 +201,21 @@ impl fmt::Display for TeamPosition {
     }
 }
 
-#[derive(Debug)]
+/// Returns the name of the queue of a game, e.g. `Ranked Solo/Duo`, `None` for custom games.
+pub fn queue_name(queue: Option<Queue>) -> &'static str {
+    match queue {
+        Some(Queue::SUMMONERS_RIFT_5V5_RANKED_SOLO) => "Ranked Solo/Duo",
+        Some(Queue::SUMMONERS_RIFT_5V5_RANKED_FLEX) => "Ranked Flex",
+        Some(Queue::SUMMONERS_RIFT_5V5_DRAFT_PICK) => "Draft Pick",
+        Some(Queue::SUMMONERS_RIFT_5V5_BLIND_PICK) => "Blind Pick",
+        Some(Queue::HOWLING_ABYSS_5V5_ARAM) => "ARAM",
+        Some(Queue::SUMMONERS_RIFT_CLASH) => "Clash",
+        Some(_) => "Other queue",
+        None => "Custom",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GameResult {
     Win,
     Loss,
@@ -205,6 +227,68 @@ pub enum ParticipantGetterError {
     ParticipantNotFound,
 }
 
+/// Performance of a player, along with the totals of their team it is compared to.
+/// Values are summed when aggregating several games.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Performance {
+    /// Kills of the team.
+    pub team_kills: i32,
+    pub damage_to_champions: i32,
+    /// Damage dealt to champions by the team.
+    pub team_damage_to_champions: i32,
+    pub gold_earned: i32,
+    pub vision_score: i32,
+    pub control_wards_bought: i32,
+    /// Epic monsters (dragons, barons and heralds) taken down by the player and by their team,
+    /// only available in recent games.
+    pub epic_monsters: Option<(i32, i32)>,
+}
+
+impl Performance {
+    fn new(info: &match_v5::Info, participant: &Participant, team: &Team) -> Self {
+        let teammates = || {
+            info.participants
+                .iter()
+                .filter(|p| p.team_id == participant.team_id)
+        };
+
+        let epic_monsters = participant.challenges.as_ref().and_then(|challenges| {
+            let takedowns = challenges.dragon_takedowns?
+                + challenges.baron_takedowns?
+                + challenges.rift_herald_takedowns?;
+            let objectives = &team.objectives;
+            let team_kills =
+                objectives.dragon.kills + objectives.baron.kills + objectives.rift_herald.kills;
+            Some((takedowns, team_kills))
+        });
+
+        Self {
+            team_kills: teammates().map(|p| p.kills).sum(),
+            damage_to_champions: participant.total_damage_dealt_to_champions,
+            team_damage_to_champions: teammates().map(|p| p.total_damage_dealt_to_champions).sum(),
+            gold_earned: participant.gold_earned,
+            vision_score: participant.vision_score,
+            control_wards_bought: participant.vision_wards_bought_in_game,
+            epic_monsters,
+        }
+    }
+
+    pub fn add(&mut self, other: &Self) {
+        self.team_kills += other.team_kills;
+        self.damage_to_champions += other.damage_to_champions;
+        self.team_damage_to_champions += other.team_damage_to_champions;
+        self.gold_earned += other.gold_earned;
+        self.vision_score += other.vision_score;
+        self.control_wards_bought += other.control_wards_bought;
+        self.epic_monsters = match (self.epic_monsters, other.epic_monsters) {
+            (Some((takedowns, total)), Some((other_takedowns, other_total))) => {
+                Some((takedowns + other_takedowns, total + other_total))
+            }
+            (epic_monsters, None) | (None, epic_monsters) => epic_monsters,
+        };
+    }
+}
+
 pub trait ParticipantGetter {
     fn get_participant(&self, summoner: &Summoner) -> Result<&Participant, ParticipantGetterError>;
 }
@@ -218,6 +302,21 @@ impl ParticipantGetter for match_v5::Info {
     }
 }
 
+pub trait LaneOpponentGetter {
+    /// Returns the participant playing the same position in the other team.
+    fn get_lane_opponent(&self, participant: &Participant) -> Option<&Participant>;
+}
+
+impl LaneOpponentGetter for match_v5::Info {
+    fn get_lane_opponent(&self, participant: &Participant) -> Option<&Participant> {
+        let position = TeamPosition::try_from(participant.team_position.clone()).ok()?;
+
+        self.participants.iter().find(|p| {
+            p.team_position == position.to_riot_api_string() && p.team_id != participant.team_id
+        })
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum TeamGetterError {
     #[error("Failed to find team")]
@@ -269,6 +368,12 @@ impl Kda {
         }
     }
 
+    /// Returns the KDA, or the number of takedowns for games without deaths.
+    pub fn get_kda_or_takedowns(&self) -> f64 {
+        let Self(kills, _, assists) = self;
+        self.get_kda().unwrap_or_else(|| f64::from(kills + assists))
+    }
+
     pub fn add(&mut self, other: &Self) {
         let Self(kills, deaths, assists) = self;
         let Self(other_kills, other_deaths, other_assists) = other;
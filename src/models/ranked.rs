@@ -1,7 +1,9 @@
+use super::matches::MatchId;
 use riven::{
     consts::{Division, QueueType, Tier},
     models::league_v4::LeagueEntry,
 };
+use std::{collections::HashMap, fmt};
 
 #[derive(Debug)]
 pub struct RankedInfo {
@@ -40,3 +42,230 @@ impl RankedInfo {
         Some(self.wins as f32 / total as f32)
     }
 }
+
+impl RankedInfo {
+    /// LP counted from the bottom of Iron IV, see [`absolute_lp`].
+    pub fn absolute_lp(&self) -> i32 {
+        absolute_lp(self.tier, self.division, self.lp)
+    }
+
+    /// Short representation of the rank, e.g. `G2 45`.
+    pub fn short_rank(&self) -> String {
+        short_rank(self.tier, self.division, self.lp)
+    }
+}
+
+impl fmt::Display for RankedInfo {
+    /// Formats the rank, e.g. `GOLD II - 45 LP`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tier)?;
+        match self.division {
+            Some(division) if !self.tier.is_apex() => write!(f, " {division}")?,
+            _ => {}
+        }
+        write!(f, " - {} LP", self.lp)
+    }
+}
+
+/// Rank of a summoner in a queue at a point in time.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RankSnapshot {
+    pub queue: QueueType,
+    pub tier: Tier,
+    pub division: Option<Division>,
+    pub lp: i32,
+    pub wins: i32,
+    pub losses: i32,
+    /// Time of the snapshot, in milliseconds since the epoch.
+    pub timestamp: i64,
+}
+
+impl RankSnapshot {
+    /// Returns `None` for unranked entries.
+    pub fn from_entry(entry: &LeagueEntry, timestamp: i64) -> Option<Self> {
+        Some(Self {
+            queue: entry.queue_type.clone(),
+            tier: entry.tier.filter(|tier| tier.is_ranked())?,
+            division: entry.rank,
+            lp: entry.league_points,
+            wins: entry.wins,
+            losses: entry.losses,
+            timestamp,
+        })
+    }
+
+    /// Whether both snapshots have the same rank and games.
+    pub fn same_rank(&self, other: &Self) -> bool {
+        (self.tier, self.division, self.lp, self.wins, self.losses)
+            == (
+                other.tier,
+                other.division,
+                other.lp,
+                other.wins,
+                other.losses,
+            )
+    }
+
+    pub const fn games(&self) -> i32 {
+        self.wins + self.losses
+    }
+
+    /// LP counted from the bottom of Iron IV, see [`absolute_lp`].
+    pub fn absolute_lp(&self) -> i32 {
+        absolute_lp(self.tier, self.division, self.lp)
+    }
+
+    /// Short representation of the rank, e.g. `G2 45`.
+    pub fn short_rank(&self) -> String {
+        short_rank(self.tier, self.division, self.lp)
+    }
+}
+
+/// Tiers below the apex tiers, from the lowest.
+const DIVIDED_TIERS: [Tier; 7] = [
+    Tier::IRON,
+    Tier::BRONZE,
+    Tier::SILVER,
+    Tier::GOLD,
+    Tier::PLATINUM,
+    Tier::EMERALD,
+    Tier::DIAMOND,
+];
+
+/// Divisions of a tier, from the lowest.
+const DIVISIONS: [Division; 4] = [Division::IV, Division::III, Division::II, Division::I];
+
+const LP_PER_DIVISION: i32 = 100;
+const LP_PER_TIER: i32 = 4 * LP_PER_DIVISION;
+
+/// LP counted from the bottom of Iron IV, so that ranks can be compared and subtracted.
+/// Apex tiers share the same ladder, starting at Master 0 LP.
+pub fn absolute_lp(tier: Tier, division: Option<Division>, lp: i32) -> i32 {
+    let Some(tier_index) = DIVIDED_TIERS.iter().position(|divided| *divided == tier) else {
+        return DIVIDED_TIERS.len() as i32 * LP_PER_TIER + lp;
+    };
+    let division_index = division
+        .and_then(|division| DIVISIONS.iter().position(|d| *d == division))
+        .unwrap_or(0);
+
+    tier_index as i32 * LP_PER_TIER + division_index as i32 * LP_PER_DIVISION + lp
+}
+
+/// Formats LP counted from the bottom of Iron IV as a rank, e.g. `GOLD II - 45 LP`.
+/// Ranks above Diamond I are shown as Master, as the apex tiers depend on the ladder.
+pub fn format_absolute_lp(absolute_lp: i32) -> String {
+    let absolute_lp = absolute_lp.max(0);
+    let apex_lp = DIVIDED_TIERS.len() as i32 * LP_PER_TIER;
+    if absolute_lp >= apex_lp {
+        return format!("{} - {} LP", Tier::MASTER, absolute_lp - apex_lp);
+    }
+
+    let tier = DIVIDED_TIERS[(absolute_lp / LP_PER_TIER) as usize];
+    let division = DIVISIONS[(absolute_lp % LP_PER_TIER / LP_PER_DIVISION) as usize];
+    format!("{tier} {division} - {} LP", absolute_lp % LP_PER_DIVISION)
+}
+
+/// Short representation of a rank, e.g. `G2 45`.
+fn short_rank(tier: Tier, division: Option<Division>, lp: i32) -> String {
+    let letter = match tier {
+        Tier::IRON => "I",
+        Tier::BRONZE => "B",
+        Tier::SILVER => "S",
+        Tier::GOLD => "G",
+        Tier::PLATINUM => "P",
+        Tier::EMERALD => "E",
+        Tier::DIAMOND => "D",
+        Tier::MASTER => "M",
+        Tier::GRANDMASTER => "GM",
+        _ => "C",
+    };
+    match division {
+        Some(division) if !tier.is_apex() => {
+            format!("{letter}{} {lp}", u8::from(division))
+        }
+        _ => format!("{letter} {lp}"),
+    }
+}
+
+/// Attributes the LP difference between two consecutive snapshots to the game played between them.
+/// Snapshots separated by more than one game cannot be attributed and are skipped.
+///
+/// `snapshots` are sorted chronologically, `games` are the IDs and end times of the games of the queue.
+pub fn lp_changes(snapshots: &[RankSnapshot], games: &[(MatchId, i64)]) -> HashMap<MatchId, i32> {
+    snapshots
+        .windows(2)
+        .filter(|pair| pair[1].games() - pair[0].games() == 1)
+        .filter_map(|pair| {
+            let (before, after) = (&pair[0], &pair[1]);
+            let mut played = games
+                .iter()
+                .filter(|(_, end)| before.timestamp < *end && *end <= after.timestamp);
+
+            match (played.next(), played.next()) {
+                (Some((id, _)), None) => {
+                    Some((id.clone(), after.absolute_lp() - before.absolute_lp()))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn snapshot(
+        tier: Tier,
+        division: Division,
+        lp: i32,
+        games: i32,
+        timestamp: i64,
+    ) -> RankSnapshot {
+        RankSnapshot {
+            queue: QueueType::RANKED_SOLO_5x5,
+            tier,
+            division: Some(division),
+            lp,
+            wins: games,
+            losses: 0,
+            timestamp,
+        }
+    }
+
+    #[test_case(Tier::IRON, Division::IV, 0 => 0 ; "bottom")]
+    #[test_case(Tier::GOLD, Division::II, 45 => 1445 ; "gold")]
+    #[test_case(Tier::MASTER, Division::I, 120 => 2920 ; "apex")]
+    fn test_absolute_lp(tier: Tier, division: Division, lp: i32) -> i32 {
+        snapshot(tier, division, lp, 0, 0).absolute_lp()
+    }
+
+    #[test_case(1445 => "GOLD II - 45 LP" ; "gold")]
+    #[test_case(0 => "IRON IV - 0 LP" ; "bottom")]
+    #[test_case(2920 => "MASTER - 120 LP" ; "apex")]
+    fn test_format_absolute_lp(absolute_lp: i32) -> String {
+        format_absolute_lp(absolute_lp)
+    }
+
+    #[test]
+    fn test_lp_changes() {
+        let snapshots = [
+            snapshot(Tier::GOLD, Division::III, 90, 10, 1000),
+            // Promotion after a single game
+            snapshot(Tier::GOLD, Division::II, 10, 11, 2000),
+            // Two games between the snapshots
+            snapshot(Tier::GOLD, Division::II, 20, 13, 5000),
+        ];
+        let games = [
+            ("promotion".to_string(), 1500),
+            ("first".to_string(), 3000),
+            ("second".to_string(), 4000),
+        ];
+
+        assert_eq!(
+            lp_changes(&snapshots, &games),
+            HashMap::from([("promotion".to_string(), 20)])
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Summary of the rank and recent games of a player, used by the views showing several players.
+
+use super::{
+    champion_stats::GameStats,
+    form::{self, GameOutcome, Streak},
+    matches::{MatchInfo, MatchPlayerInfo, TeamPosition},
+    ranked::RankedInfo,
+};
+use crate::api::account::RiotId;
+use riven::{
+    consts::{Champion, Tier},
+    models::{league_v4::LeagueEntry, summoner_v4::Summoner},
+};
+use std::collections::HashMap;
+
+pub struct PlayerSummary {
+    pub riot_id: RiotId,
+    /// Ranked solo information, `None` if unranked.
+    pub ranked: Option<RankedInfo>,
+    /// Statistics of the recent games.
+    pub stats: GameStats,
+    /// Games played in each role, from the most played.
+    pub roles: Vec<(TeamPosition, u32)>,
+    /// Statistics of each champion, from the most played.
+    pub champions: Vec<(Champion, GameStats)>,
+    /// Outcomes of the recent games, from the most recent.
+    pub outcomes: Vec<GameOutcome>,
+}
+
+impl PlayerSummary {
+    /// Summarizes the games, `matches` being sorted from the most recent.
+    /// Games that cannot be parsed (e.g. without position) are ignored.
+    pub fn new(
+        riot_id: RiotId,
+        ranked: Option<LeagueEntry>,
+        matches: &[MatchInfo],
+        summoner: &Summoner,
+    ) -> Self {
+        let match_infos = matches
+            .iter()
+            .filter_map(|game| MatchPlayerInfo::from_match_info(game, summoner).ok())
+            .collect::<Vec<_>>();
+
+        let mut stats = GameStats::new();
+        let mut roles = HashMap::new();
+        let mut champions = HashMap::new();
+        for match_info in &match_infos {
+            stats.add_game(match_info);
+            *roles.entry(match_info.team_position).or_insert(0) += 1;
+            champions
+                .entry(match_info.champion)
+                .or_insert_with(GameStats::new)
+                .add_game(match_info);
+        }
+
+        let mut roles = roles.into_iter().collect::<Vec<_>>();
+        roles.sort_by_key(|(role, games)| (std::cmp::Reverse(*games), *role));
+        let mut champions = champions.into_iter().collect::<Vec<_>>();
+        champions.sort_by_key(|(champion, stats)| {
+            (std::cmp::Reverse(stats.total_games()), champion.name())
+        });
+
+        Self {
+            riot_id,
+            ranked: ranked
+                .and_then(RankedInfo::from_entry)
+                .filter(|ranked| ranked.tier != Tier::UNRANKED),
+            stats,
+            roles,
+            champions,
+            outcomes: matches
+                .iter()
+                .filter_map(|game| GameOutcome::from_match_info(game, summoner))
+                .collect(),
+        }
+    }
+
+    /// Returns the streak the player is currently on, `None` without recent games.
+    pub fn streak(&self) -> Option<Streak> {
+        form::current_streak(&self.outcomes)
+    }
+
+    /// Returns the most played role, `None` without recent games.
+    pub fn main_role(&self) -> Option<TeamPosition> {
+        self.roles.first().map(|(role, _)| *role)
+    }
+
+    /// Returns the statistics of a champion, if played in the recent games.
+    pub fn champion(&self, champion: Champion) -> Option<&GameStats> {
+        self.champions
+            .iter()
+            .find(|(played, _)| *played == champion)
+            .map(|(_, stats)| stats)
+    }
+
+    /// Returns the share of the recent games played in each role, from the most played.
+    pub fn role_shares(&self) -> impl Iterator<Item = (TeamPosition, f64)> + '_ {
+        let total = f64::from(self.stats.total_games());
+        self.roles
+            .iter()
+            .map(move |(role, games)| (*role, f64::from(*games) / total))
+    }
+}
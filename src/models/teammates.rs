@@ -0,0 +1,207 @@
+//! Players appearing in the team of the summoner, and how the games with them went.
+
+use super::matches::{GameResult, MatchInfo, ParticipantGetter, TeamPosition};
+use crate::api::account::RiotId;
+use riven::models::{match_v5::Participant, summoner_v4::Summoner};
+use std::collections::{BTreeMap, HashMap};
+
+/// Player in the team of the summoner in a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Appearance {
+    pub puuid: String,
+    pub riot_id: String,
+    pub position: Option<TeamPosition>,
+}
+
+impl Appearance {
+    fn from_participant(participant: &Participant) -> Self {
+        Self {
+            puuid: participant.puuid.clone(),
+            riot_id: format!(
+                "{}#{}",
+                participant
+                    .riot_id_game_name
+                    .as_deref()
+                    .unwrap_or("Unknown"),
+                participant.riot_id_tagline
+            ),
+            position: participant.team_position.clone().try_into().ok(),
+        }
+    }
+}
+
+/// Games played along a teammate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Teammate {
+    /// Riot ID in the most recent game played together.
+    pub riot_id: String,
+    pub wins: u32,
+    pub losses: u32,
+    /// Games played in each position by the teammate.
+    pub positions: BTreeMap<TeamPosition, u32>,
+}
+
+impl Teammate {
+    pub const fn games(&self) -> u32 {
+        self.wins + self.losses
+    }
+
+    /// Returns the positions played by the teammate, from the most played.
+    pub fn positions(&self) -> Vec<TeamPosition> {
+        let mut positions = self.positions.iter().collect::<Vec<_>>();
+        positions.sort_by_key(|(position, games)| (std::cmp::Reverse(**games), **position));
+        positions
+            .into_iter()
+            .map(|(position, _)| *position)
+            .collect()
+    }
+}
+
+/// Teammates of the summoner, identified by their PUUID so that renamed players are merged.
+#[derive(Debug, Default)]
+pub struct Teammates {
+    teammates: HashMap<String, Teammate>,
+}
+
+impl Teammates {
+    /// Collects the teammates of the summoner, `matches` being sorted from the most recent.
+    pub fn from_matches(matches: &[MatchInfo], summoner: &Summoner) -> Self {
+        let mut teammates = Self::default();
+        for match_info in matches {
+            if let Some((result, appearances)) = team_appearances(match_info, summoner) {
+                teammates.add_game(result, appearances);
+            }
+        }
+        teammates
+    }
+
+    /// Adds a game, which has to be older than the ones already added.
+    pub fn add_game(&mut self, result: GameResult, appearances: Vec<Appearance>) {
+        for appearance in appearances {
+            let teammate = self
+                .teammates
+                .entry(appearance.puuid)
+                .or_insert_with(|| Teammate {
+                    riot_id: appearance.riot_id,
+                    wins: 0,
+                    losses: 0,
+                    positions: BTreeMap::new(),
+                });
+            match result {
+                GameResult::Win => teammate.wins += 1,
+                GameResult::Loss => teammate.losses += 1,
+            }
+            if let Some(position) = appearance.position {
+                *teammate.positions.entry(position).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Returns the teammates met in at least `min_games` games, from the most frequent.
+    pub fn frequent(self, min_games: u32) -> Vec<Teammate> {
+        let mut teammates = self
+            .teammates
+            .into_values()
+            .filter(|teammate| teammate.games() >= min_games)
+            .collect::<Vec<_>>();
+        teammates.sort_by(|a, b| {
+            b.games()
+                .cmp(&a.games())
+                .then(b.wins.cmp(&a.wins))
+                .then_with(|| a.riot_id.cmp(&b.riot_id))
+        });
+        teammates
+    }
+}
+
+/// Returns the result of the game and the other players of the summoner's team.
+fn team_appearances(
+    match_info: &MatchInfo,
+    summoner: &Summoner,
+) -> Option<(GameResult, Vec<Appearance>)> {
+    let participant = match_info.info.get_participant(summoner).ok()?;
+    let result = if participant.win {
+        GameResult::Win
+    } else {
+        GameResult::Loss
+    };
+    let appearances = match_info
+        .info
+        .participants
+        .iter()
+        .filter(|p| p.team_id == participant.team_id && p.puuid != participant.puuid)
+        .map(Appearance::from_participant)
+        .collect();
+
+    Some((result, appearances))
+}
+
+/// Returns whether `duo` played in the team of the summoner.
+pub fn played_with(match_info: &MatchInfo, summoner: &Summoner, duo: &RiotId) -> bool {
+    let Ok(participant) = match_info.info.get_participant(summoner) else {
+        return false;
+    };
+
+    match_info.info.participants.iter().any(|p| {
+        p.team_id == participant.team_id
+            && p.puuid != participant.puuid
+            && p.riot_id_game_name
+                .as_deref()
+                .is_some_and(|name| duo.matches(name, &p.riot_id_tagline))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn appearance(puuid: &str, position: Option<TeamPosition>) -> Appearance {
+        Appearance {
+            puuid: puuid.to_string(),
+            riot_id: format!("{puuid}#EUW"),
+            position,
+        }
+    }
+
+    #[test]
+    fn test_frequent() {
+        let mut teammates = Teammates::default();
+        teammates.add_game(
+            GameResult::Win,
+            vec![
+                appearance("duo", Some(TeamPosition::Jungle)),
+                appearance("random", Some(TeamPosition::Top)),
+            ],
+        );
+        teammates.add_game(
+            GameResult::Loss,
+            vec![appearance("duo", Some(TeamPosition::Support))],
+        );
+        teammates.add_game(
+            GameResult::Win,
+            vec![appearance("duo", Some(TeamPosition::Jungle))],
+        );
+
+        let frequent = teammates.frequent(2);
+        assert_eq!(frequent.len(), 1);
+        assert_eq!(frequent[0].riot_id, "duo#EUW");
+        assert_eq!((frequent[0].wins, frequent[0].losses), (2, 1));
+        assert_eq!(
+            frequent[0].positions(),
+            [TeamPosition::Jungle, TeamPosition::Support]
+        );
+    }
+
+    #[test]
+    fn test_riot_id_of_most_recent_game() {
+        let mut teammates = Teammates::default();
+        let renamed = |riot_id: &str| Appearance {
+            riot_id: riot_id.to_string(),
+            ..appearance("duo", None)
+        };
+        teammates.add_game(GameResult::Win, vec![renamed("new#EUW")]);
+        teammates.add_game(GameResult::Win, vec![renamed("old#EUW")]);
+
+        assert_eq!(teammates.frequent(1)[0].riot_id, "new#EUW");
+    }
+}
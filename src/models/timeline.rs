@@ -0,0 +1,398 @@
+//! Events of the timeline of a game (kills, wards, objectives and purchases),
+//! from the point of view of the summoner.
+
+use super::matches::{MatchInfo, ParticipantGetter};
+use riven::{
+    consts::{Map, Team},
+    models::{match_v5::EventsTimeLine, summoner_v4::Summoner},
+};
+use std::collections::BTreeMap;
+
+/// End of the early game, in minutes.
+pub const EARLY_GAME_MINUTES: i64 = 15;
+
+/// Length of the periods of the death timing distribution, in minutes.
+pub const DEATH_PERIOD_MINUTES: i64 = 5;
+
+/// Periods from which deaths are counted together, e.g. after 30 minutes.
+pub const DEATH_PERIODS: i64 = 6;
+
+/// Largest coordinate of Summoner's Rift, on both axes.
+const MAP_SIZE: i32 = 15_000;
+
+const MINUTE_MS: i64 = 60_000;
+
+/// Part taken by the summoner in the first kill of the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirstBlood {
+    Kill,
+    Assist,
+    Death,
+    Uninvolved,
+}
+
+/// Objectives whose control is tracked, in the order they are usually taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Objective {
+    Dragon,
+    Voidgrubs,
+    Herald,
+    Tower,
+    Baron,
+    Inhibitor,
+}
+
+impl Objective {
+    pub const ALL: [Self; 6] = [
+        Self::Dragon,
+        Self::Voidgrubs,
+        Self::Herald,
+        Self::Tower,
+        Self::Baron,
+        Self::Inhibitor,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Dragon => "Dragon",
+            Self::Voidgrubs => "Voidgrubs",
+            Self::Herald => "Herald",
+            Self::Tower => "Tower",
+            Self::Baron => "Baron",
+            Self::Inhibitor => "Inhibitor",
+        }
+    }
+
+    fn from_event(event: &EventsTimeLine) -> Option<Self> {
+        match event.r#type.as_str() {
+            "ELITE_MONSTER_KILL" => match event.monster_type.as_deref()? {
+                "DRAGON" => Some(Self::Dragon),
+                "HORDE" => Some(Self::Voidgrubs),
+                "RIFTHERALD" => Some(Self::Herald),
+                "BARON_NASHOR" => Some(Self::Baron),
+                _ => None,
+            },
+            "BUILDING_KILL" => match event.building_type.as_deref()? {
+                "TOWER_BUILDING" => Some(Self::Tower),
+                "INHIBITOR_BUILDING" => Some(Self::Inhibitor),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Death {
+    /// Time of the death, in milliseconds since the start of the game.
+    pub timestamp: i64,
+    /// Position on the map, `(0, 0)` being the corner of the blue base.
+    pub position: Option<(i32, i32)>,
+}
+
+/// First objective of a kind taken in a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirstObjective {
+    pub timestamp: i64,
+    /// Whether it was taken by the team of the summoner.
+    pub by_team: bool,
+}
+
+/// Events of a game concerning the summoner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameEvents {
+    /// `None` if nobody died in the game.
+    pub first_blood: Option<FirstBlood>,
+    /// Deaths of the summoner, chronologically.
+    pub deaths: Vec<Death>,
+    pub wards_placed: u32,
+    pub wards_killed: u32,
+    pub first_objectives: BTreeMap<Objective, FirstObjective>,
+    /// Items bought by the summoner and when, chronologically.
+    pub purchases: Vec<(i64, i32)>,
+}
+
+impl GameEvents {
+    /// Collects the events of the summoner, `None` if the game has no timeline.
+    pub fn from_match_info(game: &MatchInfo, summoner: &Summoner) -> Option<Self> {
+        let timeline = game.timeline.as_ref()?;
+        let participant = game.info.get_participant(summoner).ok()?;
+        let events = timeline.frames.iter().flat_map(|frame| &frame.events);
+
+        Some(Self::from_events(
+            events,
+            participant.participant_id,
+            participant.team_id,
+        ))
+    }
+
+    fn from_events<'a>(
+        events: impl IntoIterator<Item = &'a EventsTimeLine>,
+        participant_id: i32,
+        team: Team,
+    ) -> Self {
+        let mut game_events = Self {
+            first_blood: None,
+            deaths: Vec::new(),
+            wards_placed: 0,
+            wards_killed: 0,
+            first_objectives: BTreeMap::new(),
+            purchases: Vec::new(),
+        };
+
+        for event in events {
+            let is_summoner = |id: Option<i32>| id == Some(participant_id);
+
+            match event.r#type.as_str() {
+                "CHAMPION_KILL" => {
+                    let assisted = event
+                        .assisting_participant_ids
+                        .as_ref()
+                        .is_some_and(|ids| ids.contains(&participant_id));
+                    if game_events.first_blood.is_none() {
+                        game_events.first_blood = Some(if is_summoner(event.killer_id) {
+                            FirstBlood::Kill
+                        } else if assisted {
+                            FirstBlood::Assist
+                        } else if is_summoner(event.victim_id) {
+                            FirstBlood::Death
+                        } else {
+                            FirstBlood::Uninvolved
+                        });
+                    }
+                    if is_summoner(event.victim_id) {
+                        game_events.deaths.push(Death {
+                            timestamp: event.timestamp,
+                            position: event.position.as_ref().map(|p| (p.x, p.y)),
+                        });
+                    }
+                }
+                "WARD_PLACED" if is_summoner(event.creator_id) => game_events.wards_placed += 1,
+                "WARD_KILL" if is_summoner(event.killer_id) => game_events.wards_killed += 1,
+                "ITEM_PURCHASED" if is_summoner(event.participant_id) => {
+                    if let Some(item) = event.item_id {
+                        game_events.purchases.push((event.timestamp, item));
+                    }
+                }
+                _ => {
+                    if let Some(objective) = Objective::from_event(event) {
+                        // Monsters are credited to the killing team, buildings to the team losing them
+                        let by_team = match objective {
+                            Objective::Tower | Objective::Inhibitor => {
+                                event.team_id.is_some_and(|owner| owner != team)
+                            }
+                            _ => event.killer_team_id == Some(team),
+                        };
+                        game_events
+                            .first_objectives
+                            .entry(objective)
+                            .or_insert(FirstObjective {
+                                timestamp: event.timestamp,
+                                by_team,
+                            });
+                    }
+                }
+            }
+        }
+
+        game_events
+    }
+}
+
+/// Events of several games, added up.
+#[derive(Debug, Clone, Default)]
+pub struct EventStats {
+    pub games: u32,
+    /// Games of each part taken in the first blood.
+    pub first_blood_kills: u32,
+    pub first_blood_assists: u32,
+    pub first_blood_deaths: u32,
+    pub deaths: u32,
+    /// Deaths before `EARLY_GAME_MINUTES`.
+    pub early_deaths: u32,
+    /// Deaths in each period of `DEATH_PERIOD_MINUTES`, the last one counting every later death.
+    pub death_periods: [u32; DEATH_PERIODS as usize + 1],
+    /// Positions of the deaths on Summoner's Rift.
+    pub death_positions: Vec<(i32, i32)>,
+    pub wards_placed: u32,
+    pub wards_killed: u32,
+    /// For each objective, the games it was first taken by the team of the summoner,
+    /// the games it was taken in and the sum of the times it was first taken.
+    pub objectives: BTreeMap<Objective, (u32, u32, i64)>,
+    /// Times the first core item was completed, in the games one was.
+    pub first_core_items: Vec<i64>,
+}
+
+impl EventStats {
+    /// Adds a game, `is_core` telling whether an item is part of the core build.
+    pub fn add_game(&mut self, events: &GameEvents, map: Map, is_core: impl Fn(i32) -> bool) {
+        self.games += 1;
+
+        match events.first_blood {
+            Some(FirstBlood::Kill) => self.first_blood_kills += 1,
+            Some(FirstBlood::Assist) => self.first_blood_assists += 1,
+            Some(FirstBlood::Death) => self.first_blood_deaths += 1,
+            Some(FirstBlood::Uninvolved) | None => {}
+        }
+
+        for death in &events.deaths {
+            self.deaths += 1;
+            if death.timestamp < EARLY_GAME_MINUTES * MINUTE_MS {
+                self.early_deaths += 1;
+            }
+            let period = (death.timestamp / (DEATH_PERIOD_MINUTES * MINUTE_MS)).min(DEATH_PERIODS);
+            self.death_periods[period as usize] += 1;
+            if let (Some(position), Map::SUMMONERS_RIFT) = (death.position, map) {
+                self.death_positions.push(position);
+            }
+        }
+
+        self.wards_placed += events.wards_placed;
+        self.wards_killed += events.wards_killed;
+
+        for (&objective, first) in &events.first_objectives {
+            let (by_team, taken, time) = self.objectives.entry(objective).or_default();
+            *by_team += u32::from(first.by_team);
+            *taken += 1;
+            *time += first.timestamp;
+        }
+
+        if let Some(&(timestamp, _)) = events.purchases.iter().find(|(_, item)| is_core(*item)) {
+            self.first_core_items.push(timestamp);
+        }
+    }
+
+    /// Returns the share of the games in which the summoner killed or assisted the first blood.
+    pub fn first_blood_participation(&self) -> Option<f64> {
+        (self.games > 0).then(|| {
+            f64::from(self.first_blood_kills + self.first_blood_assists) / f64::from(self.games)
+        })
+    }
+
+    /// Returns the share of the games in which an objective was first taken by the team of the summoner,
+    /// and the average time it was first taken at, in milliseconds.
+    pub fn objective_control(&self, objective: Objective) -> Option<(f64, i64)> {
+        let &(by_team, taken, time) = self.objectives.get(&objective)?;
+        (taken > 0).then(|| {
+            (
+                f64::from(by_team) / f64::from(taken),
+                time / i64::from(taken),
+            )
+        })
+    }
+
+    /// Returns the number of deaths in each cell of a `width` x `height` grid laid over the map,
+    /// rows from the top (the red base side).
+    pub fn death_map(&self, width: usize, height: usize) -> Vec<Vec<u32>> {
+        let mut grid = vec![vec![0; width]; height];
+        let cell = |coordinate: i32, cells: usize| {
+            let ratio = f64::from(coordinate.clamp(0, MAP_SIZE - 1)) / f64::from(MAP_SIZE);
+            (ratio * cells as f64) as usize
+        };
+
+        for &(x, y) in &self.death_positions {
+            let column = cell(x, width);
+            let row = height - 1 - cell(y, height);
+            grid[row][column] += 1;
+        }
+
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const ME: i32 = 3;
+
+    fn event(value: serde_json::Value) -> EventsTimeLine {
+        serde_json::from_value(value).expect("Invalid event")
+    }
+
+    fn events() -> Vec<EventsTimeLine> {
+        vec![
+            event(json!({ "type": "WARD_PLACED", "timestamp": 60_000, "creatorId": ME })),
+            event(
+                json!({ "type": "ITEM_PURCHASED", "timestamp": 70_000, "participantId": ME, "itemId": 1055 }),
+            ),
+            event(json!({
+                "type": "CHAMPION_KILL", "timestamp": 200_000, "killerId": 8, "victimId": 2,
+                "assistingParticipantIds": [ME], "position": { "x": 5000, "y": 5000 }
+            })),
+            event(json!({
+                "type": "CHAMPION_KILL", "timestamp": 500_000, "killerId": 7, "victimId": ME,
+                "position": { "x": 14_000, "y": 1000 }
+            })),
+            event(
+                json!({ "type": "ELITE_MONSTER_KILL", "timestamp": 400_000, "monsterType": "DRAGON", "killerTeamId": 100 }),
+            ),
+            event(
+                json!({ "type": "ELITE_MONSTER_KILL", "timestamp": 900_000, "monsterType": "DRAGON", "killerTeamId": 200 }),
+            ),
+            event(
+                json!({ "type": "BUILDING_KILL", "timestamp": 700_000, "buildingType": "TOWER_BUILDING", "teamId": 100 }),
+            ),
+            event(
+                json!({ "type": "ITEM_PURCHASED", "timestamp": 650_000, "participantId": ME, "itemId": 3157 }),
+            ),
+            event(json!({
+                "type": "CHAMPION_KILL", "timestamp": 1_900_000, "killerId": 9, "victimId": ME,
+                "position": { "x": 10_000, "y": 14_000 }
+            })),
+        ]
+    }
+
+    #[test]
+    fn test_game_events() {
+        let events = GameEvents::from_events(&events(), ME, Team::BLUE);
+
+        assert_eq!(events.first_blood, Some(FirstBlood::Assist));
+        assert_eq!(
+            events
+                .deaths
+                .iter()
+                .map(|death| death.timestamp)
+                .collect::<Vec<_>>(),
+            [500_000, 1_900_000]
+        );
+        assert_eq!(events.wards_placed, 1);
+        assert_eq!(
+            events.first_objectives.get(&Objective::Dragon),
+            Some(&FirstObjective {
+                timestamp: 400_000,
+                by_team: true
+            })
+        );
+        // A tower of the blue side was destroyed by the red side
+        assert_eq!(
+            events.first_objectives.get(&Objective::Tower),
+            Some(&FirstObjective {
+                timestamp: 700_000,
+                by_team: false
+            })
+        );
+        assert_eq!(events.purchases, [(70_000, 1055), (650_000, 3157)]);
+    }
+
+    #[test]
+    fn test_event_stats() {
+        let events = GameEvents::from_events(&events(), ME, Team::BLUE);
+        let mut stats = EventStats::default();
+        stats.add_game(&events, Map::SUMMONERS_RIFT, |item| item == 3157);
+
+        assert_eq!((stats.deaths, stats.early_deaths), (2, 1));
+        assert_eq!(stats.death_periods, [0, 1, 0, 0, 0, 0, 1]);
+        assert_eq!(stats.first_blood_participation(), Some(1.0));
+        assert_eq!(
+            stats.objective_control(Objective::Dragon),
+            Some((1.0, 400_000))
+        );
+        assert_eq!(stats.objective_control(Objective::Baron), None);
+        assert_eq!(stats.first_core_items, [650_000]);
+
+        // Bottom right, then top right
+        assert_eq!(stats.death_map(3, 3), [[0, 0, 1], [0, 0, 0], [0, 0, 1]]);
+    }
+}
@@ -0,0 +1,276 @@
+//! Evolution of the performance over the games: rolling averages, weekly aggregates and trends.
+
+use super::matches::{GameResult, MatchInfo, MatchPlayerInfo};
+use riven::models::summoner_v4::Summoner;
+use std::fmt;
+
+/// Length of a week, in milliseconds.
+const WEEK_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+
+/// Offset of the first Monday after the epoch (a Thursday), so that weeks start on Mondays.
+const MONDAY_OFFSET_MS: i64 = 4 * 24 * 60 * 60 * 1000;
+
+/// Statistics of a game used to follow the performance over time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameSample {
+    /// Start of the game, in milliseconds since the epoch.
+    pub timestamp: i64,
+    pub result: GameResult,
+    pub kda: f64,
+    pub cs_per_min: f64,
+    /// Gold difference with the lane opponent at 15 minutes.
+    pub gd15: Option<f64>,
+}
+
+impl GameSample {
+    pub fn new(match_info: &MatchInfo, player_info: &MatchPlayerInfo) -> Self {
+        Self {
+            timestamp: match_info.info.game_creation,
+            result: player_info.game_result,
+            kda: player_info.kda.get_kda_or_takedowns(),
+            cs_per_min: f64::from(player_info.minions_killed)
+                / (f64::from(player_info.time_played) / 60.0),
+            gd15: player_info
+                .lane_diffs
+                .get(&15)
+                .map(|diff| f64::from(diff.gold)),
+        }
+    }
+
+    /// Returns the samples of the games, from the oldest, `matches` being sorted from the most recent.
+    pub fn from_matches(matches: &[MatchInfo], summoner: &Summoner) -> Vec<Self> {
+        matches
+            .iter()
+            .rev()
+            .filter_map(|game| {
+                let player_info = MatchPlayerInfo::from_match_info(game, summoner).ok()?;
+                Some(Self::new(game, &player_info))
+            })
+            .collect()
+    }
+}
+
+/// Statistic followed over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Winrate,
+    Kda,
+    CsPerMin,
+    Gd15,
+}
+
+impl Metric {
+    pub const ALL: [Self; 4] = [Self::Winrate, Self::Kda, Self::CsPerMin, Self::Gd15];
+
+    /// Returns the value of a game, the winrate being 1 for a win and 0 for a loss.
+    pub fn value(self, sample: &GameSample) -> Option<f64> {
+        match self {
+            Self::Winrate => Some(if sample.result == GameResult::Win {
+                1.0
+            } else {
+                0.0
+            }),
+            Self::Kda => Some(sample.kda),
+            Self::CsPerMin => Some(sample.cs_per_min),
+            Self::Gd15 => sample.gd15,
+        }
+    }
+
+    /// Returns the values of the games that have one.
+    pub fn values(self, samples: &[GameSample]) -> Vec<f64> {
+        samples
+            .iter()
+            .filter_map(|sample| self.value(sample))
+            .collect()
+    }
+
+    /// Change over the games below which the metric is considered stable.
+    const fn tolerance(self) -> f64 {
+        match self {
+            Self::Winrate => 0.05,
+            Self::Kda => 0.3,
+            Self::CsPerMin => 0.3,
+            Self::Gd15 => 100.0,
+        }
+    }
+
+    /// Formats a value of the metric, e.g. `55%` for a winrate.
+    pub fn format(self, value: f64) -> String {
+        match self {
+            Self::Winrate => format!("{:.0}%", value * 100.0),
+            Self::Kda | Self::CsPerMin => format!("{value:.1}"),
+            Self::Gd15 => format!("{value:+.0}"),
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Winrate => write!(f, "WR"),
+            Self::Kda => write!(f, "KDA"),
+            Self::CsPerMin => write!(f, "CS/M"),
+            Self::Gd15 => write!(f, "GD@15"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Improving,
+    Declining,
+    Stable,
+}
+
+impl Trend {
+    /// Returns the trend of the values of a metric, from the change given by their linear regression.
+    pub fn of(values: &[f64], metric: Metric) -> Self {
+        let change = slope(values) * values.len().saturating_sub(1) as f64;
+        if change > metric.tolerance() {
+            Self::Improving
+        } else if change < -metric.tolerance() {
+            Self::Declining
+        } else {
+            Self::Stable
+        }
+    }
+
+    pub const fn arrow(self) -> char {
+        match self {
+            Self::Improving => '↑',
+            Self::Declining => '↓',
+            Self::Stable => '→',
+        }
+    }
+}
+
+/// Returns the average of each `window` consecutive values, or of all of them if there are fewer.
+pub fn rolling_average(values: &[f64], window: usize) -> Vec<f64> {
+    if values.is_empty() || window == 0 {
+        return Vec::new();
+    }
+
+    values
+        .windows(window.min(values.len()))
+        .map(|window| window.iter().sum::<f64>() / window.len() as f64)
+        .collect()
+}
+
+/// Returns the slope of the least squares line of the values, spaced by one.
+fn slope(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    if values.len() < 2 {
+        return 0.0;
+    }
+
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = values.iter().sum::<f64>() / n;
+    let (covariance, variance) =
+        values
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(covariance, variance), (x, y)| {
+                let dx = x as f64 - mean_x;
+                (covariance + dx * (y - mean_y), variance + dx * dx)
+            });
+
+    covariance / variance
+}
+
+/// Games played during a week, starting on Monday.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Week {
+    /// Number of weeks before the current week.
+    pub weeks_ago: i64,
+    pub samples: Vec<GameSample>,
+}
+
+impl Week {
+    /// Returns the average of a metric over the games of the week.
+    pub fn average(&self, metric: Metric) -> Option<f64> {
+        let values = metric.values(&self.samples);
+        (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+    }
+}
+
+/// Groups the games by week, from the oldest, `samples` being sorted from the oldest.
+/// `now` is the current time in milliseconds since the epoch, from which the weeks are counted.
+pub fn weekly(samples: &[GameSample], now: i64) -> Vec<Week> {
+    let week_of = |timestamp: i64| (timestamp - MONDAY_OFFSET_MS).div_euclid(WEEK_MS);
+    let current_week = week_of(now);
+
+    let mut weeks: Vec<Week> = Vec::new();
+    for sample in samples {
+        let weeks_ago = current_week - week_of(sample.timestamp);
+        match weeks.last_mut() {
+            Some(week) if week.weeks_ago == weeks_ago => week.samples.push(*sample),
+            _ => weeks.push(Week {
+                weeks_ago,
+                samples: vec![*sample],
+            }),
+        }
+    }
+
+    weeks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+    fn sample(day: i64, won: bool) -> GameSample {
+        GameSample {
+            // 2024-01-01 is a Monday
+            timestamp: 1_704_067_200_000 + day * DAY_MS,
+            result: if won {
+                GameResult::Win
+            } else {
+                GameResult::Loss
+            },
+            kda: 3.0,
+            cs_per_min: 7.0,
+            gd15: None,
+        }
+    }
+
+    #[test_case(&[1.0, 2.0, 3.0, 4.0], 2 => vec![1.5, 2.5, 3.5] ; "window")]
+    #[test_case(&[1.0, 3.0], 5 => vec![2.0] ; "fewer values than the window")]
+    #[test_case(&[], 3 => Vec::<f64>::new() ; "no values")]
+    fn test_rolling_average(values: &[f64], window: usize) -> Vec<f64> {
+        rolling_average(values, window)
+    }
+
+    #[test_case(&[0.4, 0.5, 0.6] => Trend::Improving ; "improving")]
+    #[test_case(&[0.6, 0.5, 0.4] => Trend::Declining ; "declining")]
+    #[test_case(&[0.5, 0.52, 0.5] => Trend::Stable ; "stable")]
+    #[test_case(&[0.5] => Trend::Stable ; "single value")]
+    fn test_trend(values: &[f64]) -> Trend {
+        Trend::of(values, Metric::Winrate)
+    }
+
+    #[test]
+    fn test_weekly() {
+        // Monday, Sunday, then the Monday after and three weeks later
+        let samples = [
+            sample(0, true),
+            sample(6, false),
+            sample(7, true),
+            sample(28, true),
+        ];
+        // Wednesday of the week after the last game
+        let weeks = weekly(&samples, sample(37, true).timestamp);
+
+        assert_eq!(
+            weeks
+                .iter()
+                .map(|week| (week.weeks_ago, week.samples.len()))
+                .collect::<Vec<_>>(),
+            [(5, 2), (4, 1), (1, 1)]
+        );
+        assert_eq!(weeks[0].average(Metric::Winrate), Some(0.5));
+        assert_eq!(weeks[0].average(Metric::Gd15), None);
+    }
+}
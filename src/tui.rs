@@ -0,0 +1,48 @@
+//! Full-screen terminal UI to browse the cached games.
+
+use crate::{api::tooling::static_data::StaticNames, models::matches::MatchInfo};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::DefaultTerminal;
+use riven::models::summoner_v4::Summoner;
+use state::Browser;
+
+mod state;
+mod ui;
+
+/// Runs the browser of the games until the user quits.
+pub fn run(
+    matches: Vec<MatchInfo>,
+    summoner: &Summoner,
+    names: &StaticNames,
+) -> anyhow::Result<()> {
+    let mut browser = Browser::new(matches, summoner);
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut browser, names);
+    ratatui::restore();
+
+    Ok(result?)
+}
+
+fn event_loop(
+    terminal: &mut DefaultTerminal,
+    browser: &mut Browser,
+    names: &StaticNames,
+) -> std::io::Result<()> {
+    while !browser.should_quit() {
+        terminal.draw(|frame| ui::draw(frame, browser, names))?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            // The terminal is in raw mode, so Ctrl+C does not interrupt the program
+            if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+                break;
+            }
+            browser.handle_key(key.code);
+        }
+    }
+
+    Ok(())
+}
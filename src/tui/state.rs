@@ -0,0 +1,267 @@
+//! State of the browser: games, filters, selection and current view.
+
+use crate::models::matches::{GameResult, MatchInfo, MatchPlayerInfo, TeamPosition};
+use crossterm::event::KeyCode;
+use riven::{
+    consts::{Champion, Queue},
+    models::summoner_v4::Summoner,
+};
+
+/// Number of games skipped by page up / page down.
+const PAGE: usize = 10;
+
+/// Cached game, along with the statistics of the summoner in it.
+pub struct Game {
+    pub info: MatchInfo,
+    pub player: MatchPlayerInfo,
+}
+
+impl Game {
+    pub fn queue(&self) -> Queue {
+        self.info.info.queue_id
+    }
+}
+
+/// Games shown in the list, every game when a criterion is `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Filter {
+    pub champion: Option<Champion>,
+    pub role: Option<TeamPosition>,
+    pub result: Option<GameResult>,
+    pub queue: Option<Queue>,
+}
+
+impl Filter {
+    pub fn matches(&self, player: &MatchPlayerInfo, queue: Queue) -> bool {
+        self.champion
+            .is_none_or(|champion| player.champion == champion)
+            && self.role.is_none_or(|role| player.team_position == role)
+            && self
+                .result
+                .is_none_or(|result| player.game_result == result)
+            && self.queue.is_none_or(|filtered| queue == filtered)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    List,
+    /// Details of the selected game.
+    Detail,
+}
+
+pub struct Browser {
+    /// Games from the most recent.
+    games: Vec<Game>,
+    filter: Filter,
+    /// Indices of the games matching the filter.
+    visible: Vec<usize>,
+    /// Index of the selected game among the visible games.
+    selected: usize,
+    view: View,
+    quit: bool,
+    /// PUUID of the summoner, whose row is highlighted in the details.
+    puuid: String,
+}
+
+impl Browser {
+    /// Creates a browser of the cached games of the summoner, sorted from the most recent.
+    pub fn new(matches: Vec<MatchInfo>, summoner: &Summoner) -> Self {
+        let games = matches
+            .into_iter()
+            .filter_map(|info| {
+                let player = MatchPlayerInfo::from_match_info(&info, summoner).ok()?;
+                Some(Game { info, player })
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            visible: (0..games.len()).collect(),
+            games,
+            filter: Filter::default(),
+            selected: 0,
+            view: View::List,
+            quit: false,
+            puuid: summoner.puuid.clone(),
+        }
+    }
+
+    pub const fn filter(&self) -> &Filter {
+        &self.filter
+    }
+
+    pub fn puuid(&self) -> &str {
+        &self.puuid
+    }
+
+    pub const fn view(&self) -> View {
+        self.view
+    }
+
+    pub const fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    pub const fn total_games(&self) -> usize {
+        self.games.len()
+    }
+
+    /// Returns the games matching the filter.
+    pub fn visible(&self) -> impl Iterator<Item = &Game> {
+        self.visible.iter().map(|&index| &self.games[index])
+    }
+
+    /// Returns the index of the selected game among the visible games, `None` if there are none.
+    pub fn selected_index(&self) -> Option<usize> {
+        (!self.visible.is_empty()).then_some(self.selected)
+    }
+
+    pub fn selected(&self) -> Option<&Game> {
+        self.visible
+            .get(self.selected)
+            .map(|&index| &self.games[index])
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) {
+        match (self.view, key) {
+            (_, KeyCode::Char('q')) => self.quit = true,
+            (View::Detail, KeyCode::Esc | KeyCode::Backspace) => self.view = View::List,
+            (View::List, KeyCode::Esc) => self.quit = true,
+            (View::List, KeyCode::Enter) if self.selected().is_some() => self.view = View::Detail,
+            (_, KeyCode::Down | KeyCode::Char('j')) => self.select(self.selected.saturating_add(1)),
+            (_, KeyCode::Up | KeyCode::Char('k')) => self.select(self.selected.saturating_sub(1)),
+            (_, KeyCode::PageDown) => self.select(self.selected.saturating_add(PAGE)),
+            (_, KeyCode::PageUp) => self.select(self.selected.saturating_sub(PAGE)),
+            (_, KeyCode::Home | KeyCode::Char('g')) => self.select(0),
+            (_, KeyCode::End | KeyCode::Char('G')) => self.select(usize::MAX),
+            (View::List, KeyCode::Char('c')) => {
+                let champions = self.values(
+                    |game| game.player.champion,
+                    |champion| champion.name().unwrap_or("Unknown").to_string(),
+                );
+                self.filter.champion = cycle(self.filter.champion, &champions);
+                self.apply_filter();
+            }
+            (View::List, KeyCode::Char('r')) => {
+                let roles = self.values(|game| game.player.team_position, |&role| role);
+                self.filter.role = cycle(self.filter.role, &roles);
+                self.apply_filter();
+            }
+            (View::List, KeyCode::Char('o')) => {
+                self.filter.result =
+                    cycle(self.filter.result, &[GameResult::Win, GameResult::Loss]);
+                self.apply_filter();
+            }
+            (View::List, KeyCode::Char('u')) => {
+                let queues = self.values(Game::queue, |&queue| queue);
+                self.filter.queue = cycle(self.filter.queue, &queues);
+                self.apply_filter();
+            }
+            (View::List, KeyCode::Char('x')) => {
+                self.filter = Filter::default();
+                self.apply_filter();
+            }
+            _ => {}
+        }
+    }
+
+    /// Selects a visible game, clamped to the last one.
+    fn select(&mut self, index: usize) {
+        self.selected = index.min(self.visible.len().saturating_sub(1));
+    }
+
+    /// Returns the distinct values of the games, sorted by `key`.
+    fn values<T: Copy + PartialEq, K: Ord>(
+        &self,
+        value: impl Fn(&Game) -> T,
+        key: impl Fn(&T) -> K,
+    ) -> Vec<T> {
+        let mut values = Vec::new();
+        for game in &self.games {
+            let value = value(game);
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+        values.sort_by_key(key);
+        values
+    }
+
+    fn apply_filter(&mut self) {
+        self.visible = self
+            .games
+            .iter()
+            .enumerate()
+            .filter(|(_, game)| self.filter.matches(&game.player, game.queue()))
+            .map(|(index, _)| index)
+            .collect();
+        self.select(0);
+    }
+}
+
+/// Returns the value following `current` in `values`, `None` (no filter) after the last one.
+fn cycle<T: Copy + PartialEq>(current: Option<T>, values: &[T]) -> Option<T> {
+    match current {
+        None => values.first().copied(),
+        Some(current) => values
+            .iter()
+            .position(|&value| value == current)
+            .and_then(|position| values.get(position + 1))
+            .copied(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn game(champion: Champion, team_position: TeamPosition, won: bool) -> MatchPlayerInfo {
+        let result = if won {
+            GameResult::Win
+        } else {
+            GameResult::Loss
+        };
+        MatchPlayerInfo::test(champion, team_position, result)
+    }
+
+    #[test_case(None => Some(1) ; "first value")]
+    #[test_case(Some(1) => Some(2) ; "next value")]
+    #[test_case(Some(3) => None ; "back to no filter")]
+    #[test_case(Some(4) => None ; "value no longer present")]
+    fn test_cycle(current: Option<i32>) -> Option<i32> {
+        cycle(current, &[1, 2, 3])
+    }
+
+    #[test]
+    fn test_cycle_without_values() {
+        assert_eq!(cycle::<i32>(None, &[]), None);
+    }
+
+    #[test]
+    fn test_filter() {
+        let ahri_mid_win = game(Champion::AHRI, TeamPosition::Mid, true);
+        let zed_mid_loss = game(Champion::ZED, TeamPosition::Mid, false);
+        let solo = Queue::SUMMONERS_RIFT_5V5_RANKED_SOLO;
+        let flex = Queue::SUMMONERS_RIFT_5V5_RANKED_FLEX;
+
+        let all = Filter::default();
+        assert!(all.matches(&ahri_mid_win, solo) && all.matches(&zed_mid_loss, flex));
+
+        let mid_wins = Filter {
+            role: Some(TeamPosition::Mid),
+            result: Some(GameResult::Win),
+            ..Default::default()
+        };
+        assert!(mid_wins.matches(&ahri_mid_win, solo));
+        assert!(!mid_wins.matches(&zed_mid_loss, solo));
+
+        let ahri_flex = Filter {
+            champion: Some(Champion::AHRI),
+            queue: Some(flex),
+            ..Default::default()
+        };
+        assert!(!ahri_flex.matches(&ahri_mid_win, solo));
+        assert!(ahri_flex.matches(&ahri_mid_win, flex));
+    }
+}
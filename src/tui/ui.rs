@@ -0,0 +1,368 @@
+//! Rendering of the list of games and of the details of a game.
+
+use super::state::{Browser, Filter, Game, View};
+use crate::{
+    api::tooling::static_data::StaticNames,
+    display::{
+        theme::{self, Theme},
+        utils::time_ago,
+    },
+    models::{
+        loadout::Loadout,
+        matches::{queue_name, GameResult},
+    },
+};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Block, Cell, Chart, Dataset, GraphType, Paragraph, Row, Table, TableState},
+    Frame,
+};
+use riven::{
+    consts::Team,
+    models::match_v5::{Participant, Team as MatchTeam},
+};
+
+const LIST_HELP: &str =
+    "↑/↓ move  Enter details  c champion  r role  o result  u queue  x clear filters  q quit";
+const DETAIL_HELP: &str = "↑/↓ previous/next game  Esc back  q quit";
+
+pub fn draw(frame: &mut Frame, browser: &Browser, names: &StaticNames) {
+    let theme = theme::current();
+
+    match (browser.view(), browser.selected()) {
+        (View::Detail, Some(game)) => draw_detail(frame, game, browser.puuid(), names, theme),
+        _ => draw_list(frame, browser, theme),
+    }
+}
+
+fn draw_list(frame: &mut Frame, browser: &Browser, theme: &Theme) {
+    let [title, table, help] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let shown = browser.visible().count();
+    let mut status =
+        vec![Span::from(format!("{shown} of {} cached games", browser.total_games())).bold()];
+    status.extend(filter_spans(browser.filter(), theme));
+    frame.render_widget(Line::from(status), title);
+
+    let rows = browser.visible().map(|game| {
+        let player = &game.player;
+        let minutes = f64::from(player.time_played) / 60.0;
+        let gold_diff = player
+            .lane_diffs
+            .get(&15)
+            .map_or_else(|| Span::from("-"), |diff| signed(diff.gold, theme));
+
+        Row::new([
+            Cell::from(time_ago(game.info.end_timestamp())),
+            Cell::from(queue_name(Some(game.queue()))),
+            Cell::from(result_span(player.game_result, theme)),
+            Cell::from(player.champion.name().unwrap_or("Unknown")),
+            Cell::from(player.team_position.to_string()),
+            Cell::from(player.kda.to_string()),
+            Cell::from(format!(
+                "{} ({:.1})",
+                player.minions_killed,
+                f64::from(player.minions_killed) / minutes.max(1.0)
+            )),
+            Cell::from(gold_diff),
+            Cell::from(
+                player
+                    .lane_opponent
+                    .and_then(|opponent| opponent.name())
+                    .unwrap_or("-"),
+            ),
+            Cell::from(duration(game.info.info.game_duration)),
+        ])
+    });
+    let header = Row::new([
+        "Played", "Queue", "Result", "Champion", "Role", "KDA", "CS (/m)", "GD@15", "Against",
+        "Duration",
+    ])
+    .style(Style::new().fg(color(theme.accent)).bold());
+
+    let widths = [
+        Constraint::Length(8),
+        Constraint::Length(15),
+        Constraint::Length(6),
+        Constraint::Length(14),
+        Constraint::Length(4),
+        Constraint::Length(8),
+        Constraint::Length(10),
+        Constraint::Length(6),
+        Constraint::Length(14),
+        Constraint::Length(8),
+    ];
+    let table_widget = Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    let mut state = TableState::default().with_selected(browser.selected_index());
+    frame.render_stateful_widget(table_widget, table, &mut state);
+
+    if shown == 0 {
+        let empty = table.inner(ratatui::layout::Margin::new(2, 2));
+        frame.render_widget(Paragraph::new("No game matches the filters"), empty);
+    }
+
+    frame.render_widget(Line::from(LIST_HELP).dim(), help);
+}
+
+/// Returns the active filters, to be appended to the status line.
+fn filter_spans(filter: &Filter, theme: &Theme) -> Vec<Span<'static>> {
+    let mut active = Vec::new();
+    if let Some(champion) = filter.champion {
+        active.push(("Champion", champion.name().unwrap_or("Unknown").to_string()));
+    }
+    if let Some(role) = filter.role {
+        active.push(("Role", role.to_string()));
+    }
+    if let Some(result) = filter.result {
+        active.push((
+            "Result",
+            match result {
+                GameResult::Win => "Win".to_string(),
+                GameResult::Loss => "Loss".to_string(),
+            },
+        ));
+    }
+    if let Some(queue) = filter.queue {
+        active.push(("Queue", queue_name(Some(queue)).to_string()));
+    }
+
+    active
+        .into_iter()
+        .flat_map(|(name, value)| {
+            [
+                Span::from(format!(" - {name}: ")),
+                Span::from(value).fg(color(theme.accent)),
+            ]
+        })
+        .collect()
+}
+
+fn draw_detail(frame: &mut Frame, game: &Game, puuid: &str, names: &StaticNames, theme: &Theme) {
+    let [title, blue, red, chart, help] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Min(8),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let info = &game.info.info;
+    frame.render_widget(
+        Line::from(vec![
+            Span::from(format!("{} - ", game.info.id)).bold(),
+            Span::from(queue_name(Some(game.queue()))),
+            Span::from(format!(
+                " - {} - {} - ",
+                duration(info.game_duration),
+                time_ago(game.info.end_timestamp())
+            )),
+            result_span(game.player.game_result, theme),
+        ]),
+        title,
+    );
+
+    for (side, area) in [(Team::BLUE, blue), (Team::RED, red)] {
+        let team = info.teams.iter().find(|team| team.team_id == side);
+        let participants = info
+            .participants
+            .iter()
+            .filter(|participant| participant.team_id == side);
+        frame.render_widget(
+            team_table(side, team, participants, puuid, names, theme),
+            area,
+        );
+    }
+
+    draw_gold_chart(frame, chart, game, theme);
+    frame.render_widget(Line::from(DETAIL_HELP).dim(), help);
+}
+
+fn team_table<'a>(
+    side: Team,
+    team: Option<&MatchTeam>,
+    participants: impl Iterator<Item = &'a Participant>,
+    puuid: &str,
+    names: &StaticNames,
+    theme: &Theme,
+) -> Table<'a> {
+    let mut title = vec![Span::from(if side == Team::BLUE {
+        " Blue side"
+    } else {
+        " Red side"
+    })];
+    if let Some(team) = team {
+        title.push(Span::from(" - "));
+        title.push(result_span(
+            if team.win {
+                GameResult::Win
+            } else {
+                GameResult::Loss
+            },
+            theme,
+        ));
+    }
+    title.push(Span::from(" "));
+
+    let rows = participants
+        .map(|participant| {
+            let name = participant
+                .riot_id_game_name
+                .clone()
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| participant.summoner_name.clone());
+            let items = Loadout::from_participant(participant)
+                .items
+                .into_iter()
+                .map(|item| names.item_name(item))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let row = Row::new([
+                name,
+                participant.champion_name.clone(),
+                format!(
+                    "{}/{}/{}",
+                    participant.kills, participant.deaths, participant.assists
+                ),
+                (participant.total_minions_killed + participant.neutral_minions_killed).to_string(),
+                participant.gold_earned.to_string(),
+                participant.total_damage_dealt_to_champions.to_string(),
+                participant.vision_score.to_string(),
+                items,
+            ]);
+            if participant.puuid == puuid {
+                row.style(Style::new().fg(color(theme.accent)).bold())
+            } else {
+                row
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let widths = [
+        Constraint::Length(16),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(4),
+        Constraint::Length(6),
+        Constraint::Length(7),
+        Constraint::Length(6),
+        Constraint::Fill(1),
+    ];
+    Table::new(rows, widths)
+        .header(
+            Row::new([
+                "Player", "Champion", "KDA", "CS", "Gold", "Damage", "Vision", "Items",
+            ])
+            .bold(),
+        )
+        .block(Block::bordered().title(Line::from(title)))
+}
+
+/// Draws the gold difference with the lane opponent at each minute.
+fn draw_gold_chart(frame: &mut Frame, area: Rect, game: &Game, theme: &Theme) {
+    let diffs = &game.player.lane_diffs;
+    let opponent = game
+        .player
+        .lane_opponent
+        .and_then(|opponent| opponent.name())
+        .unwrap_or("the lane opponent");
+    let block = Block::bordered().title(format!(" Gold difference with {opponent} "));
+
+    let Some(&last_minute) = diffs.keys().last() else {
+        frame.render_widget(
+            Paragraph::new("No timeline or lane opponent for this game").block(block),
+            area,
+        );
+        return;
+    };
+
+    let points = diffs
+        .iter()
+        .map(|(&minute, diff)| (f64::from(minute), f64::from(diff.gold)))
+        .collect::<Vec<_>>();
+    let zero = [(0.0, 0.0), (f64::from(last_minute), 0.0)];
+    // Symmetric bounds rounded to 500 gold, so that zero is in the middle
+    let bound = diffs
+        .values()
+        .map(|diff| diff.gold.unsigned_abs())
+        .max()
+        .unwrap_or(0)
+        .div_ceil(500)
+        .max(1)
+        * 500;
+    let bound = f64::from(bound);
+
+    let chart = Chart::new(vec![
+        Dataset::default()
+            .graph_type(GraphType::Line)
+            .marker(Marker::Braille)
+            .style(Style::new().fg(Color::DarkGray))
+            .data(&zero),
+        Dataset::default()
+            .graph_type(GraphType::Line)
+            .marker(Marker::Braille)
+            .style(Style::new().fg(color(theme.accent)))
+            .data(&points),
+    ])
+    .block(block)
+    .x_axis(
+        Axis::default()
+            .bounds([0.0, f64::from(last_minute)])
+            .labels(["0".to_string(), format!("{last_minute} min")]),
+    )
+    .y_axis(Axis::default().bounds([-bound, bound]).labels([
+        format!("{}", -bound),
+        "0".to_string(),
+        format!("+{bound}"),
+    ]));
+    frame.render_widget(chart, area);
+}
+
+fn result_span(result: GameResult, theme: &Theme) -> Span<'static> {
+    match result {
+        GameResult::Win => Span::from("WIN").fg(color(theme.win)),
+        GameResult::Loss => Span::from("LOSS").fg(color(theme.loss)),
+    }
+}
+
+fn signed(value: i32, theme: &Theme) -> Span<'static> {
+    let span = Span::from(format!("{value:+}"));
+    match value.cmp(&0) {
+        std::cmp::Ordering::Greater => span.fg(color(theme.positive)),
+        std::cmp::Ordering::Less => span.fg(color(theme.negative)),
+        std::cmp::Ordering::Equal => span,
+    }
+}
+
+fn duration(seconds: i64) -> String {
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Converts a color of the theme to a color of the terminal UI.
+const fn color(color: termcolor::Color) -> Color {
+    match color {
+        termcolor::Color::Black => Color::Black,
+        termcolor::Color::Blue => Color::Blue,
+        termcolor::Color::Green => Color::Green,
+        termcolor::Color::Red => Color::Red,
+        termcolor::Color::Cyan => Color::Cyan,
+        termcolor::Color::Magenta => Color::Magenta,
+        termcolor::Color::Yellow => Color::Yellow,
+        termcolor::Color::White => Color::White,
+        termcolor::Color::Ansi256(index) => Color::Indexed(index),
+        termcolor::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
+        _ => Color::Reset,
+    }
+}